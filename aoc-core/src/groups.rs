@@ -0,0 +1,146 @@
+use anyhow::Context;
+use std::{
+    io::{BufRead, Lines},
+    iter::Enumerate,
+};
+
+use crate::{LineError, ParseError};
+
+/// A block of consecutive lines from a grouped input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// 1-based line number of each line, for error reporting
+    pub line_numbers: Vec<usize>,
+    pub lines: Vec<String>,
+}
+
+impl Group {
+    /// Parses every line with `parse_line`, reporting failures at the line
+    /// they happened on
+    pub fn parse_lines<T>(
+        &self,
+        mut parse_line: impl FnMut(&str) -> Result<T, LineError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.line_numbers
+            .iter()
+            .zip(self.lines.iter())
+            .map(|(&line_number, line)| {
+                parse_line(line).map_err(|err| err.at_line(line_number, line))
+            })
+            .collect()
+    }
+}
+
+pub fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Splits a reader into groups of lines, e.g. the blank line separated
+/// blocks of day 1. The last group is returned even without a separator
+/// after it, runs of separators never produce empty groups and `\r` line
+/// endings are stripped.
+pub struct Groups<R> {
+    lines: Enumerate<Lines<R>>,
+    is_separator: fn(&str) -> bool,
+}
+
+impl<R: BufRead> Groups<R> {
+    /// Groups separated by blank lines
+    pub fn new(reader: R) -> Self {
+        Self::with_separator(reader, is_blank)
+    }
+
+    pub fn with_separator(reader: R, is_separator: fn(&str) -> bool) -> Self {
+        Groups {
+            lines: reader.lines().enumerate(),
+            is_separator,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Groups<R> {
+    type Item = anyhow::Result<Group>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group = Group {
+            line_numbers: Vec::new(),
+            lines: Vec::new(),
+        };
+
+        for (i, line) in self.lines.by_ref() {
+            let mut line = match line.context("reading line to string") {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err)),
+            };
+            if line.ends_with('\r') {
+                line.pop();
+            }
+
+            if (self.is_separator)(&line) {
+                if group.lines.is_empty() {
+                    continue;
+                }
+                return Some(Ok(group));
+            }
+
+            group.line_numbers.push(i + 1);
+            group.lines.push(line);
+        }
+
+        if group.lines.is_empty() {
+            None
+        } else {
+            Some(Ok(group))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Groups;
+    use crate::LineError;
+
+    fn group_lines(input: &str) -> Vec<Vec<String>> {
+        Groups::new(input.as_bytes())
+            .map(|group| group.unwrap().lines)
+            .collect()
+    }
+
+    #[test]
+    fn last_group_is_flushed() {
+        assert_eq!(vec![vec!["1", "2"], vec!["3"]], group_lines("1\n2\n\n3"));
+    }
+
+    #[test]
+    fn repeated_separators_and_crlf() {
+        assert_eq!(
+            vec![vec!["1", "2"], vec!["3"]],
+            group_lines("\r\n1\r\n2\r\n\r\n\r\n  \n3\r\n\r\n")
+        );
+        assert!(group_lines("").is_empty());
+    }
+
+    #[test]
+    fn custom_separator() {
+        let groups: Vec<_> =
+            Groups::with_separator("a\n---\nb\n\nc".as_bytes(), |line| line == "---")
+                .map(|group| group.unwrap().lines)
+                .collect();
+
+        assert_eq!(vec![vec!["a"], vec!["b", "", "c"]], groups);
+    }
+
+    #[test]
+    fn parse_errors_keep_line_numbers() {
+        let group = Groups::new("1\n\n2\nx".as_bytes()).nth(1).unwrap().unwrap();
+
+        let err = group
+            .parse_lines(|line| {
+                line.parse::<i32>()
+                    .map_err(|err| LineError::new(1, err.to_string()))
+            })
+            .unwrap_err();
+
+        assert_eq!(4, err.line_number);
+    }
+}
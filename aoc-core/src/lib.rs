@@ -0,0 +1,72 @@
+use anyhow::Context;
+use serde::Serialize;
+use std::{
+    fmt::{Debug, Display},
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+pub mod groups;
+mod parse_error;
+
+pub use parse_error::{attach_file_name, LineError, ParseError};
+
+/// A single day's puzzle, split into parsing and the two parts so that
+/// each step can be called (and timed) independently of the others.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed;
+    type PartOne: Debug + Display + Serialize;
+    type PartTwo: Debug + Display + Serialize;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Parsed>;
+
+    fn part_one(parsed: &Self::Parsed) -> anyhow::Result<Self::PartOne>;
+
+    fn part_two(parsed: &Self::Parsed) -> anyhow::Result<Self::PartTwo>;
+}
+
+/// A part's answer, kept both as the text shown in the terminal and as JSON
+/// so numbers stay numbers for anything reading the machine output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub text: String,
+    pub value: serde_json::Value,
+}
+
+impl Answer {
+    pub fn new(answer: &(impl Display + Serialize)) -> anyhow::Result<Self> {
+        Ok(Answer {
+            text: answer.to_string(),
+            value: serde_json::to_value(answer).context("serializing answer")?,
+        })
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+pub fn parse_file<S: Solution>(path: &Path) -> anyhow::Result<S::Parsed> {
+    let file = File::open(path).context(format!("opening file {}", path.display()))?;
+
+    S::parse(BufReader::new(file))
+        .map_err(|err| attach_file_name(err, &path.display().to_string()))
+        .context("parsing input")
+}
+
+pub fn print_answers<S: Solution>(path: &Path) -> anyhow::Result<()> {
+    let parsed = parse_file::<S>(path)?;
+
+    let part_one = S::part_one(&parsed).context("solving part one")?;
+    println!("Day {} part one: {}", S::DAY, part_one);
+
+    let part_two = S::part_two(&parsed).context("solving part two")?;
+    println!("Day {} part two: {}", S::DAY, part_two);
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use std::fmt::{self, Display};
+
+/// Where in a line parsing went wrong, before the line itself is known.
+/// Columns are 1-based and count chars rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    pub column: usize,
+    pub len: usize,
+    pub message: String,
+}
+
+impl LineError {
+    pub fn new(column: usize, message: impl Into<String>) -> Self {
+        Self::spanning(column, 1, message)
+    }
+
+    pub fn spanning(column: usize, len: usize, message: impl Into<String>) -> Self {
+        LineError {
+            column,
+            len: len.max(1),
+            message: message.into(),
+        }
+    }
+
+    /// Convenience for errors on a sub-slice of `line`, e.g. a token from `split`
+    pub fn at_slice(line: &str, slice: &str, message: impl Into<String>) -> Self {
+        let byte_offset = (slice.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let column = line
+            .get(..byte_offset)
+            .map_or(1, |before| before.chars().count() + 1);
+        Self::spanning(column, slice.chars().count(), message)
+    }
+
+    pub fn at_line(self, line_number: usize, line: &str) -> ParseError {
+        ParseError {
+            file_name: None,
+            line_number,
+            column: self.column,
+            len: self.len,
+            snippet: line.to_string(),
+            message: self.message,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file_name: Option<String>,
+    pub line_number: usize,
+    pub column: usize,
+    pub len: usize,
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line_number: usize,
+        column: usize,
+        snippet: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        LineError::new(column, message).at_line(line_number, &snippet.into())
+    }
+
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file_name = self.file_name.as_deref().unwrap_or("<input>");
+        let gutter = " ".repeat(self.line_number.to_string().len());
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter, file_name, self.line_number, self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line_number, self.snippet)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(self.len)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Fill in the file name of a `ParseError` anywhere in an error's chain,
+/// since parsers only ever see a reader.
+pub fn attach_file_name(mut err: anyhow::Error, file_name: &str) -> anyhow::Error {
+    if let Some(parse_error) = err.downcast_mut::<ParseError>() {
+        parse_error.file_name = Some(file_name.to_string());
+    }
+    err
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Context;
+
+    use super::{attach_file_name, LineError, ParseError};
+
+    #[test]
+    fn renders_caret_under_column() {
+        let error =
+            ParseError::new(12, 6, "addx x", "expected a number").with_file_name("input.txt");
+
+        let expected = [
+            "expected a number",
+            "  --> input.txt:12:6",
+            "   |",
+            "12 | addx x",
+            "   |      ^",
+        ]
+        .join("\n");
+        assert_eq!(expected, error.to_string());
+    }
+
+    #[test]
+    fn spans_a_token_found_with_split() {
+        let line = "2-4,6-x";
+        let token = line.split(['-', ',']).nth(3).unwrap();
+        let error = LineError::at_slice(line, token, "bad number").at_line(1, line);
+
+        assert_eq!(7, error.column);
+        assert!(error.to_string().ends_with("1 | 2-4,6-x\n  |       ^"));
+    }
+
+    #[test]
+    fn file_name_is_attached_through_context() {
+        let err: anyhow::Error = ParseError::new(1, 1, "?", "bad").into();
+        let err = attach_file_name(Err::<(), _>(err).context("parsing").unwrap_err(), "a.txt");
+
+        let parse_error = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(Some("a.txt".to_string()), parse_error.file_name);
+    }
+}
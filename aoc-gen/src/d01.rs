@@ -0,0 +1,16 @@
+use rand::Rng;
+
+/// `size` elves, each carrying 1 to 8 items
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let elfs: Vec<String> = (0..size)
+        .map(|_| {
+            let no_items = rng.gen_range(1..=8);
+            (0..no_items)
+                .map(|_| rng.gen_range(1_000..=60_000).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+
+    elfs.join("\n\n") + "\n\n"
+}
@@ -0,0 +1,12 @@
+use rand::{seq::SliceRandom, Rng};
+
+/// `size` rounds of the strategy guide
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let opponent = ['A', 'B', 'C'].choose(rng).expect("non empty");
+            let player = ['X', 'Y', 'Z'].choose(rng).expect("non empty");
+            format!("{} {}\n", opponent, player)
+        })
+        .collect()
+}
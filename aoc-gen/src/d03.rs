@@ -0,0 +1,61 @@
+use rand::{seq::SliceRandom, Rng};
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn pick(rng: &mut impl Rng, items: &[char], amount: usize) -> Vec<char> {
+    (0..amount)
+        .map(|_| *items.choose(rng).expect("non empty"))
+        .collect()
+}
+
+/// A rucksack drawn from `pool` plus the group `badge`, with exactly one item
+/// shared by both compartments
+fn gen_rucksack(rng: &mut impl Rng, pool: &[char], badge: char) -> String {
+    let mut pool = pool.to_vec();
+    pool.push(badge);
+    pool.shuffle(rng);
+
+    let common = pool.pop().expect("non empty pool");
+    let mut first_only: Vec<char> = pool.iter().cloned().filter(|&i| i != badge).collect();
+    let second_only = first_only.split_off(first_only.len() / 2);
+    if common != badge {
+        first_only.push(badge);
+    }
+
+    let compartment_size = rng.gen_range(2..=16);
+
+    let mut first = vec![common];
+    if common != badge {
+        first.push(badge);
+    }
+    let remaining = compartment_size - first.len();
+    first.extend(pick(rng, &first_only, remaining));
+    first.shuffle(rng);
+
+    let mut second = vec![common];
+    second.extend(pick(rng, &second_only, compartment_size - 1));
+    second.shuffle(rng);
+
+    first.into_iter().chain(second).collect()
+}
+
+/// `size` elf groups, so `3 * size` rucksacks. Every rucksack has exactly one
+/// item in both compartments and every group exactly one badge.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut items: Vec<char> = ITEMS.chars().collect();
+        items.shuffle(rng);
+        let badge = items.pop().expect("non empty items");
+
+        //Each elf draws from their own third of the other items so
+        //the badge is the only item all three can share
+        for pool in items.chunks(items.len() / 3) {
+            input.push_str(&gen_rucksack(rng, pool, badge));
+            input.push('\n');
+        }
+    }
+
+    input
+}
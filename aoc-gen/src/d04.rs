@@ -0,0 +1,14 @@
+use rand::Rng;
+
+fn gen_range_str(rng: &mut impl Rng) -> String {
+    let lower = rng.gen_range(1..=99);
+    let upper = rng.gen_range(lower..=99);
+    format!("{}-{}", lower, upper)
+}
+
+/// `size` elf pairs
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{},{}\n", gen_range_str(rng), gen_range_str(rng)))
+        .collect()
+}
@@ -0,0 +1,67 @@
+use rand::{seq::IteratorRandom, Rng};
+
+const NO_STACKS: usize = 9;
+const MAX_START_HEIGHT: usize = 8;
+
+fn draw_ship(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut lines = Vec::new();
+
+    for level in (0..height).rev() {
+        let line: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(crate_item) => format!("[{}]", crate_item),
+                None => "   ".to_string(),
+            })
+            .collect();
+        lines.push(line.join(" "));
+    }
+
+    let ids: Vec<String> = (1..=stacks.len()).map(|id| format!(" {} ", id)).collect();
+    lines.push(ids.join(" "));
+
+    lines.join("\n") + "\n"
+}
+
+/// A ship of 9 stacks followed by `size` moves. The moves are simulated while
+/// generating so that no move takes more crates than its stack holds.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    //Bottom of each stack first
+    let mut stacks: Vec<Vec<char>> = (0..NO_STACKS)
+        .map(|_| {
+            let height = rng.gen_range(1..=MAX_START_HEIGHT);
+            (0..height)
+                .map(|_| char::from(b'A' + rng.gen_range(0..26)))
+                .collect()
+        })
+        .collect();
+
+    let mut input = draw_ship(&stacks);
+    input.push('\n');
+
+    for _ in 0..size {
+        let from = (0..NO_STACKS)
+            .filter(|&i| !stacks[i].is_empty())
+            .choose(rng)
+            .expect("crates are never removed so a stack is never empty");
+        let to = (0..NO_STACKS)
+            .filter(|&i| i != from)
+            .choose(rng)
+            .expect("more than one stack");
+        let move_amount = rng.gen_range(1..=stacks[from].len());
+
+        let split_at = stacks[from].len() - move_amount;
+        let moved = stacks[from].split_off(split_at);
+        stacks[to].extend(moved);
+
+        input.push_str(&format!(
+            "move {} from {} to {}\n",
+            move_amount,
+            from + 1,
+            to + 1
+        ));
+    }
+
+    input
+}
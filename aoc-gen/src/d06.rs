@@ -0,0 +1,29 @@
+use rand::{seq::SliceRandom, Rng};
+
+/// A datastream whose first start-of-packet marker (4 distinct chars) ends at
+/// `marker_position` and whose start-of-message marker (14) ends 10 chars later
+pub fn generate_with_marker(rng: &mut impl Rng, marker_position: usize) -> String {
+    let marker_position = marker_position.max(4);
+    let prefix_len = marker_position - 3;
+
+    //Only using three letters means no window before the marker can be all
+    //distinct, and ending on a repeated letter stops the marker starting early
+    let repeated_tail = prefix_len.min(3);
+    let mut datastream: String = (0..prefix_len - repeated_tail)
+        .map(|_| *['a', 'b', 'c'].choose(rng).expect("non empty"))
+        .collect();
+    datastream.push_str(&"a".repeat(repeated_tail));
+
+    let mut distinct: Vec<char> = ('d'..='z').collect();
+    distinct.shuffle(rng);
+    datastream.extend(&distinct[..14]);
+
+    datastream.extend((0..10).map(|_| char::from(b'a' + rng.gen_range(0..26))));
+
+    datastream + "\n"
+}
+
+/// A datastream of roughly `size` chars with the first marker at `size`
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    generate_with_marker(rng, size)
+}
@@ -0,0 +1,90 @@
+use rand::{seq::SliceRandom, Rng};
+
+struct Dir {
+    name: String,
+    files: Vec<(String, i32)>,
+    children: Vec<usize>,
+}
+
+fn gen_name(rng: &mut impl Rng, taken: &[&str]) -> String {
+    loop {
+        let len = rng.gen_range(3..=8);
+        let name: String = (0..len)
+            .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+            .collect();
+        if !taken.contains(&name.as_str()) {
+            return name;
+        }
+    }
+}
+
+fn write_dir(dirs: &[Dir], i: usize, transcript: &mut Vec<String>) {
+    let dir = &dirs[i];
+    transcript.push(format!("$ cd {}", dir.name));
+    transcript.push("$ ls".to_string());
+
+    let mut listing: Vec<String> = dir
+        .children
+        .iter()
+        .map(|&child| format!("dir {}", dirs[child].name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect();
+    listing.sort();
+    transcript.extend(listing);
+
+    for &child in dir.children.iter() {
+        write_dir(dirs, child, transcript);
+        transcript.push("$ cd ..".to_string());
+    }
+}
+
+/// A terminal transcript exploring a tree of `size` directories (plus the
+/// root), each holding up to 4 files
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut dirs = vec![Dir {
+        name: "/".to_string(),
+        files: Vec::new(),
+        children: Vec::new(),
+    }];
+
+    for _ in 0..size {
+        let parent = rng.gen_range(0..dirs.len());
+        let taken: Vec<&str> = dirs[parent]
+            .children
+            .iter()
+            .map(|&child| dirs[child].name.as_str())
+            .collect();
+        let name = gen_name(rng, &taken);
+
+        dirs.push(Dir {
+            name,
+            files: Vec::new(),
+            children: Vec::new(),
+        });
+        let new_dir = dirs.len() - 1;
+        dirs[parent].children.push(new_dir);
+    }
+
+    for dir in dirs.iter_mut() {
+        let no_files = rng.gen_range(0..=4);
+        for _ in 0..no_files {
+            let taken: Vec<&str> = dir.files.iter().map(|(name, _)| name.as_str()).collect();
+            let mut name = gen_name(rng, &taken);
+            if let Some(extension) = ["txt", "dat", "log", ""].choose(rng) {
+                if !extension.is_empty() {
+                    name = format!("{}.{}", name, extension);
+                }
+            }
+            dir.files.push((name, rng.gen_range(1..=300_000)));
+        }
+    }
+
+    let mut transcript = Vec::new();
+    write_dir(&dirs, 0, &mut transcript);
+
+    transcript.join("\n") + "\n"
+}
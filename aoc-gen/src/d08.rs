@@ -0,0 +1,13 @@
+use rand::Rng;
+
+/// A `size` by `size` grid of tree heights
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| char::from(b'0' + rng.gen_range(0..=9)))
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,11 @@
+use rand::{seq::SliceRandom, Rng};
+
+/// `size` motions of up to 20 steps each
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = ['U', 'D', 'L', 'R'].choose(rng).expect("non empty");
+            format!("{} {}\n", direction, rng.gen_range(1..=20))
+        })
+        .collect()
+}
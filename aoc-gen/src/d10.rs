@@ -0,0 +1,14 @@
+use rand::Rng;
+
+/// `size` CPU instructions
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            if rng.gen_bool(0.3) {
+                "noop\n".to_string()
+            } else {
+                format!("addx {}\n", rng.gen_range(-20..=20))
+            }
+        })
+        .collect()
+}
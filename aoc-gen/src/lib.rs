@@ -0,0 +1,94 @@
+use anyhow::anyhow;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+
+/// Generate a valid puzzle input for `day`. What `size` counts depends on the
+/// day (elves, rounds, moves, grid width...), see each day's module.
+/// The same seed always produces the same input.
+pub fn generate(day: u8, size: usize, seed: u64) -> anyhow::Result<String> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let input = match day {
+        1 => d01::generate(&mut rng, size),
+        2 => d02::generate(&mut rng, size),
+        3 => d03::generate(&mut rng, size),
+        4 => d04::generate(&mut rng, size),
+        5 => d05::generate(&mut rng, size),
+        6 => d06::generate(&mut rng, size),
+        7 => d07::generate(&mut rng, size),
+        8 => d08::generate(&mut rng, size),
+        9 => d09::generate(&mut rng, size),
+        10 => d10::generate(&mut rng, size),
+        val => Err(anyhow!("No generator for day {}", val))?,
+    };
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_core::Solution;
+
+    use super::generate;
+
+    fn solve<S: Solution>(size: usize) {
+        let input = generate(S::DAY, size, 42).unwrap();
+        let parsed = S::parse(input.as_bytes()).unwrap();
+
+        S::part_one(&parsed).unwrap();
+        S::part_two(&parsed).unwrap();
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        solve::<d1::Day01>(100);
+        solve::<d2::Day02>(100);
+        solve::<d3::Day03>(100);
+        solve::<d4::Day04>(100);
+        solve::<d5::Day05>(100);
+        solve::<d6::Day06>(100);
+        solve::<d7::Day07>(100);
+        solve::<d8::Day08>(20);
+        solve::<d9::Day09>(100);
+
+        //Part two is unsolved for day 10
+        let input = generate(10, 100, 42).unwrap();
+        let parsed = d10::Day10::parse(input.as_bytes()).unwrap();
+        d10::Day10::part_one(&parsed).unwrap();
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in 1..=10 {
+            assert_eq!(generate(day, 50, 7).unwrap(), generate(day, 50, 7).unwrap());
+        }
+        assert_ne!(generate(1, 50, 7).unwrap(), generate(1, 50, 8).unwrap());
+    }
+
+    #[test]
+    fn day_6_markers_are_where_requested() {
+        for size in [4, 5, 10, 1000] {
+            let input = generate(6, size, 3).unwrap();
+            let parsed = d6::Day06::parse(input.as_bytes()).unwrap();
+
+            assert_eq!(size, d6::Day06::part_one(&parsed).unwrap());
+            assert_eq!(size + 10, d6::Day06::part_two(&parsed).unwrap());
+        }
+    }
+
+    #[test]
+    fn unknown_day_is_an_error() {
+        assert!(generate(26, 10, 0).is_err());
+    }
+}
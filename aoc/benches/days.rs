@@ -0,0 +1,114 @@
+use aoc_core::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::{fs, hint::black_box};
+
+fn read_input(day_dir: &str, input_file: &str) -> String {
+    let path = format!(
+        "{}/../{}/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        day_dir,
+        input_file
+    );
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("reading {}: {}", path, err))
+}
+
+/// A seeded synthetic input from aoc-gen, several times the size of the real one
+fn generated_input(day: u8, size: usize) -> String {
+    aoc_gen::generate(day, size, 0).unwrap_or_else(|err| panic!("generating day {}: {}", day, err))
+}
+
+fn bench_solution<S: Solution>(c: &mut Criterion, inputs: &[(&str, String)]) {
+    let mut group = c.benchmark_group(format!("d{:02}", S::DAY));
+    group.sample_size(10);
+
+    for (input_name, input) in inputs {
+        group.bench_with_input(BenchmarkId::new("parse", input_name), input, |b, input| {
+            b.iter(|| S::parse(black_box(input.as_bytes())).unwrap())
+        });
+
+        let parsed = S::parse(input.as_bytes()).unwrap();
+
+        if S::part_one(&parsed).is_ok() {
+            group.bench_with_input(
+                BenchmarkId::new("part_one", input_name),
+                &parsed,
+                |b, parsed| b.iter(|| S::part_one(black_box(parsed)).unwrap()),
+            );
+        }
+
+        if S::part_two(&parsed).is_ok() {
+            group.bench_with_input(
+                BenchmarkId::new("part_two", input_name),
+                &parsed,
+                |b, parsed| b.iter(|| S::part_two(black_box(parsed)).unwrap()),
+            );
+        }
+    }
+
+    group.finish();
+}
+
+fn d01(c: &mut Criterion) {
+    let input = read_input("d01", "my_input.txt");
+    let generated = generated_input(1, 2500);
+    bench_solution::<d1::Day01>(c, &[("my_input", input), ("generated_x10", generated)]);
+}
+
+fn d02(c: &mut Criterion) {
+    let input = read_input("d02", "my_input.txt");
+    let generated = generated_input(2, 25_000);
+    bench_solution::<d2::Day02>(c, &[("my_input", input), ("generated_x10", generated)]);
+}
+
+fn d03(c: &mut Criterion) {
+    //Size counts groups of three elves
+    let input = read_input("d03", "my_input.txt");
+    let generated = generated_input(3, 1000);
+    bench_solution::<d3::Day03>(c, &[("my_input", input), ("generated_x10", generated)]);
+}
+
+fn d04(c: &mut Criterion) {
+    let input = read_input("d04", "my_input.txt");
+    let generated = generated_input(4, 10_000);
+    bench_solution::<d4::Day04>(c, &[("my_input", input), ("generated_x10", generated)]);
+}
+
+fn d05(c: &mut Criterion) {
+    let input = read_input("d05", "my_input.txt");
+    let generated = generated_input(5, 5000);
+    bench_solution::<d5::Day05>(c, &[("my_input", input), ("generated_x10", generated)]);
+}
+
+fn d06(c: &mut Criterion) {
+    let input = read_input("d06", "my_input.txt");
+    let generated = generated_input(6, 40_000);
+    bench_solution::<d6::Day06>(c, &[("my_input", input), ("generated_x10", generated)]);
+}
+
+fn d07(c: &mut Criterion) {
+    let input = read_input("d07", "my_input.txt");
+    let generated = generated_input(7, 2000);
+    bench_solution::<d7::Day07>(c, &[("my_input", input), ("generated_x10", generated)]);
+}
+
+fn d08(c: &mut Criterion) {
+    //Size is the grid width, so twice the width is four times the trees
+    let input = read_input("d08", "my_input.txt");
+    let generated = generated_input(8, 200);
+    bench_solution::<d8::Day08>(c, &[("my_input", input), ("generated_x4", generated)]);
+}
+
+fn d09(c: &mut Criterion) {
+    let input = read_input("d09", "my_input.txt");
+    let generated = generated_input(9, 20_000);
+    bench_solution::<d9::Day09>(c, &[("my_input", input), ("generated_x10", generated)]);
+}
+
+fn d10(c: &mut Criterion) {
+    let input = read_input("d10", "input.txt");
+    let generated = generated_input(10, 1400);
+    bench_solution::<d10::Day10>(c, &[("input", input), ("generated_x10", generated)]);
+}
+
+criterion_group!(benches, d01, d02, d03, d04, d05, d06, d07, d08, d09, d10);
+criterion_main!(benches);
@@ -0,0 +1,175 @@
+use anyhow::{anyhow, Context};
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+use crate::days::{self, Part, PartRun};
+
+#[derive(Debug, Deserialize)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl ExpectedAnswer {
+    fn expected_for_part(&self, part: Part) -> Option<&String> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnswerRegistry {
+    pub answers: Vec<ExpectedAnswer>,
+}
+
+impl AnswerRegistry {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path).context(format!("reading {}", path.display()))?;
+
+        toml::from_str(&contents).context(format!("parsing {}", path.display()))
+    }
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl Verification {
+    pub fn passed(&self) -> bool {
+        matches!(self.outcome, Outcome::Pass)
+    }
+}
+
+pub fn verify_part(expected: &str, part_run: Option<&PartRun>) -> Outcome {
+    match part_run.map(|part_run| &part_run.answer) {
+        None => Outcome::Error("part was not run".to_string()),
+        Some(Err(err)) => Outcome::Error(format!("{:#}", err)),
+        Some(Ok(actual)) if actual.text == expected => Outcome::Pass,
+        Some(Ok(actual)) => Outcome::Fail {
+            expected: expected.to_string(),
+            actual: actual.text.clone(),
+        },
+    }
+}
+
+pub fn verify_answer(
+    expected_answer: &ExpectedAnswer,
+    workspace_root: &Path,
+) -> anyhow::Result<Vec<Verification>> {
+    let day = days::get_day(expected_answer.day)?;
+    let input_path = workspace_root.join(day.dir).join(&expected_answer.input);
+
+    let parts: Vec<Part> = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|&part| expected_answer.expected_for_part(part).is_some())
+        .collect();
+
+    let run_part = match parts.as_slice() {
+        [] => Err(anyhow!(
+            "No expected answers for day {} with {}",
+            expected_answer.day,
+            expected_answer.input
+        ))?,
+        [part] => Some(*part),
+        _ => None,
+    };
+
+    let verifications = match day.run(&input_path, run_part) {
+        Err(err) => parts
+            .into_iter()
+            .map(|part| Verification {
+                day: day.number,
+                input: expected_answer.input.clone(),
+                part,
+                outcome: Outcome::Error(format!("{:#}", err)),
+            })
+            .collect(),
+        Ok(day_run) => parts
+            .into_iter()
+            .filter_map(|part| {
+                let expected = expected_answer.expected_for_part(part)?;
+                let part_run = match part {
+                    Part::One => day_run.part_one.as_ref(),
+                    Part::Two => day_run.part_two.as_ref(),
+                };
+                Some(Verification {
+                    day: day.number,
+                    input: expected_answer.input.clone(),
+                    part,
+                    outcome: verify_part(expected, part_run),
+                })
+            })
+            .collect(),
+    };
+
+    Ok(verifications)
+}
+
+pub fn verify_all(
+    registry: &AnswerRegistry,
+    workspace_root: &Path,
+) -> anyhow::Result<Vec<Verification>> {
+    let mut verifications = Vec::new();
+    for expected_answer in registry.answers.iter() {
+        verifications.extend(verify_answer(expected_answer, workspace_root)?);
+    }
+    Ok(verifications)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{verify_all, AnswerRegistry, Outcome};
+    use crate::workspace_root;
+
+    #[test]
+    fn checked_in_answers_still_pass() {
+        let registry = AnswerRegistry::load(&workspace_root().join("answers.toml")).unwrap();
+        let verifications = verify_all(&registry, workspace_root()).unwrap();
+
+        let failures: Vec<_> = verifications
+            .iter()
+            .filter(|verification| !verification.passed())
+            .collect();
+
+        assert!(failures.is_empty(), "failed verifications {:?}", failures);
+    }
+
+    #[test]
+    fn wrong_answer_is_reported_as_fail() {
+        let registry: AnswerRegistry = toml::from_str(
+            r#"
+            [[answers]]
+            day = 8
+            input = "example.txt"
+            part_one = "22"
+            "#,
+        )
+        .unwrap();
+
+        let verifications = verify_all(&registry, workspace_root()).unwrap();
+
+        assert_eq!(1, verifications.len());
+        match &verifications[0].outcome {
+            Outcome::Fail { expected, actual } => {
+                assert_eq!("22", expected);
+                assert_eq!("21", actual);
+            }
+            outcome => panic!("expected a fail, got {:?}", outcome),
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use anyhow::{anyhow, Context};
+use aoc_core::{Answer, Solution};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::memory;
+
+pub const YEAR: u16 = 2022;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn try_from_number(number: u8) -> anyhow::Result<Self> {
+        match number {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            val => Err(anyhow!("{} is not a valid part, expected 1 or 2", val)),
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn selected(part: Option<Part>) -> Vec<Part> {
+        match part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub answer: anyhow::Result<Answer>,
+    pub duration: Duration,
+    pub peak_bytes: usize,
+}
+
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub parse_duration: Duration,
+    pub parse_peak_bytes: usize,
+    pub part_one: Option<PartRun>,
+    pub part_two: Option<PartRun>,
+}
+
+/// One part's result as emitted by `--format json`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub duration_ns: u64,
+    pub peak_bytes: usize,
+}
+
+impl PartRecord {
+    fn from_part_run(day: u8, part: Part, part_run: &PartRun) -> Self {
+        let (answer, error) = match &part_run.answer {
+            Ok(answer) => (Some(answer.value.clone()), None),
+            Err(err) => (None, Some(format!("{:#}", err))),
+        };
+
+        PartRecord {
+            day,
+            part: part.number(),
+            answer,
+            error,
+            duration_ns: part_run.duration.as_nanos() as u64,
+            peak_bytes: part_run.peak_bytes,
+        }
+    }
+
+    /// Record for a part that never ran because the whole day failed
+    pub fn failed(day: u8, part: Part, err: &anyhow::Error) -> Self {
+        PartRecord {
+            day,
+            part: part.number(),
+            answer: None,
+            error: Some(format!("{:#}", err)),
+            duration_ns: 0,
+            peak_bytes: 0,
+        }
+    }
+}
+
+impl DayRun {
+    pub fn records(&self) -> Vec<PartRecord> {
+        [(Part::One, &self.part_one), (Part::Two, &self.part_two)]
+            .into_iter()
+            .filter_map(|(part, part_run)| {
+                let part_run = part_run.as_ref()?;
+                Some(PartRecord::from_part_run(self.day, part, part_run))
+            })
+            .collect()
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.parse_duration
+            + self
+                .part_one
+                .iter()
+                .chain(self.part_two.iter())
+                .map(|part_run| part_run.duration)
+                .sum::<Duration>()
+    }
+
+    /// The most memory any single step needed
+    pub fn peak_bytes(&self) -> usize {
+        self.part_one
+            .iter()
+            .chain(self.part_two.iter())
+            .map(|part_run| part_run.peak_bytes)
+            .fold(self.parse_peak_bytes, usize::max)
+    }
+}
+
+type DayRunner = fn(&mut dyn BufRead, Option<Part>) -> anyhow::Result<DayRun>;
+
+pub struct Day {
+    pub number: u8,
+    pub dir: &'static str,
+    pub input_file: &'static str,
+    pub readme_file: &'static str,
+    runner: DayRunner,
+}
+
+impl Day {
+    pub fn default_input_path(&self, workspace_root: &Path) -> PathBuf {
+        workspace_root.join(self.dir).join(self.input_file)
+    }
+
+    pub fn run(&self, input_path: &Path, part: Option<Part>) -> anyhow::Result<DayRun> {
+        let input_file =
+            File::open(input_path).context(format!("opening file {}", input_path.display()))?;
+
+        self.run_reader(&mut BufReader::new(input_file), part)
+            .map_err(|err| aoc_core::attach_file_name(err, &input_path.display().to_string()))
+    }
+
+    pub fn run_reader(
+        &self,
+        reader: &mut dyn BufRead,
+        part: Option<Part>,
+    ) -> anyhow::Result<DayRun> {
+        (self.runner)(reader, part)
+    }
+
+    pub fn readme_path(&self, workspace_root: &Path) -> PathBuf {
+        workspace_root.join(self.dir).join(self.readme_file)
+    }
+}
+
+/// Runs `step`, measuring its wall time and peak allocation
+fn instrument<T>(step: impl FnOnce() -> T) -> (T, Duration, usize) {
+    let start = Instant::now();
+    let (result, peak_bytes) = memory::track_peak(step);
+    (result, start.elapsed(), peak_bytes)
+}
+
+fn run_part<T: Display + Serialize>(solve: impl FnOnce() -> anyhow::Result<T>) -> PartRun {
+    let (answer, duration, peak_bytes) = instrument(solve);
+    PartRun {
+        answer: answer.and_then(|answer| Answer::new(&answer)),
+        duration,
+        peak_bytes,
+    }
+}
+
+fn run_solution<S: Solution>(
+    reader: &mut dyn BufRead,
+    part: Option<Part>,
+) -> anyhow::Result<DayRun> {
+    let (parsed, parse_duration, parse_peak_bytes) = instrument(|| S::parse(reader));
+    let parsed = parsed.context("parsing input")?;
+
+    let part_one = match part {
+        None | Some(Part::One) => Some(run_part(|| S::part_one(&parsed))),
+        Some(Part::Two) => None,
+    };
+
+    let part_two = match part {
+        None | Some(Part::Two) => Some(run_part(|| S::part_two(&parsed))),
+        Some(Part::One) => None,
+    };
+
+    Ok(DayRun {
+        day: S::DAY,
+        parse_duration,
+        parse_peak_bytes,
+        part_one,
+        part_two,
+    })
+}
+
+pub const DAYS: [Day; 10] = [
+    Day {
+        number: 1,
+        dir: "d01",
+        input_file: "my_input.txt",
+        readme_file: "Readme.md",
+        runner: run_solution::<d1::Day01>,
+    },
+    Day {
+        number: 2,
+        dir: "d02",
+        input_file: "my_input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d2::Day02>,
+    },
+    Day {
+        number: 3,
+        dir: "d03",
+        input_file: "my_input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d3::Day03>,
+    },
+    Day {
+        number: 4,
+        dir: "d04",
+        input_file: "my_input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d4::Day04>,
+    },
+    Day {
+        number: 5,
+        dir: "d05",
+        input_file: "my_input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d5::Day05>,
+    },
+    Day {
+        number: 6,
+        dir: "d06",
+        input_file: "my_input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d6::Day06>,
+    },
+    Day {
+        number: 7,
+        dir: "d07",
+        input_file: "my_input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d7::Day07>,
+    },
+    Day {
+        number: 8,
+        dir: "d08",
+        input_file: "my_input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d8::Day08>,
+    },
+    Day {
+        number: 9,
+        dir: "d09",
+        input_file: "my_input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d9::Day09>,
+    },
+    Day {
+        number: 10,
+        dir: "d10",
+        input_file: "input.txt",
+        readme_file: "ReadMe.md",
+        runner: run_solution::<d10::Day10>,
+    },
+];
+
+pub fn get_day(number: u8) -> anyhow::Result<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| anyhow!("Day {} has not been solved", number))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_day, Part};
+
+    #[test]
+    fn records_serialize_answers_as_json_values() {
+        let day = get_day(5).unwrap();
+        let day_run = day
+            .run(
+                &day.default_input_path(crate::workspace_root()),
+                Some(Part::One),
+            )
+            .unwrap();
+
+        let records = serde_json::to_value(day_run.records()).unwrap();
+        let records = records.as_array().unwrap();
+
+        assert_eq!(1, records.len());
+        assert_eq!(5, records[0]["day"]);
+        assert_eq!(1, records[0]["part"]);
+        assert!(records[0]["answer"].is_string());
+        assert!(records[0]["duration_ns"].is_u64());
+        assert!(records[0].get("error").is_none());
+    }
+
+    #[test]
+    fn numeric_answers_stay_numbers() {
+        let answer = aoc_core::Answer::new(&24000).unwrap();
+
+        assert_eq!("24000", answer.text);
+        assert_eq!(serde_json::json!(24000), answer.value);
+    }
+}
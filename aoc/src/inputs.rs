@@ -0,0 +1,156 @@
+use anyhow::{anyhow, Context};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Resolves a puzzle's `(year, day)` to a file in the cache dir, downloading
+/// it from `base_url` with the session token the first time it is needed.
+#[derive(Debug, Clone)]
+pub struct InputManager {
+    pub cache_dir: PathBuf,
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl InputManager {
+    /// Settings from `AOC_CACHE_DIR`, `AOC_BASE_URL` and `AOC_SESSION`, falling
+    /// back to `.aoc-cache` in the workspace and the real site
+    pub fn from_env(workspace_root: &Path) -> Self {
+        InputManager {
+            cache_dir: env::var_os("AOC_CACHE_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| workspace_root.join(".aoc-cache")),
+            base_url: env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session: env::var("AOC_SESSION").ok(),
+        }
+    }
+
+    pub fn cached_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        )
+    }
+
+    pub fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| anyhow!("No session token set, export AOC_SESSION to fetch inputs"))?;
+        let url = self.input_url(year, day);
+
+        match ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+        {
+            Ok(response) => response
+                .into_string()
+                .context(format!("reading response from {}", url)),
+            Err(ureq::Error::Status(code, _)) => Err(anyhow!("{} returned status {}", url, code)),
+            Err(err) => Err(err).context(format!("requesting {}", url)),
+        }
+    }
+
+    /// Path to the cached input, fetching and caching it if it isn't there yet
+    pub fn resolve(&self, year: u16, day: u8) -> anyhow::Result<PathBuf> {
+        let path = self.cached_path(year, day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let input = self
+            .fetch(year, day)
+            .context(format!("fetching input for {} day {}", year, day))?;
+
+        let dir = path.parent().expect("cached path is inside the cache dir");
+        fs::create_dir_all(dir).context(format!("creating cache dir {}", dir.display()))?;
+
+        //Write next to the real file first so an interrupted write is never
+        //mistaken for a cached input
+        let partial_path = path.with_extension("txt.partial");
+        fs::write(&partial_path, input).context(format!("writing {}", partial_path.display()))?;
+        fs::rename(&partial_path, &path).context(format!("moving input to {}", path.display()))?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use super::InputManager;
+    use crate::stand_in_server::StandInServer;
+
+    const SESSION: &str = "test-session";
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn manager(server: &StandInServer, cache_dir: PathBuf, session: Option<&str>) -> InputManager {
+        InputManager {
+            cache_dir,
+            base_url: server.base_url(),
+            session: session.map(|session| session.to_string()),
+        }
+    }
+
+    #[test]
+    fn fetches_once_then_reads_from_cache() {
+        let server = StandInServer::start(SESSION, vec![(2022, 1, "1000\n2000\n".to_string())]);
+        let cache_dir = temp_cache_dir("cache");
+        let inputs = manager(&server, cache_dir.clone(), Some(SESSION));
+
+        let path = inputs.resolve(2022, 1).unwrap();
+        assert_eq!(cache_dir.join("2022").join("day01.txt"), path);
+        assert_eq!("1000\n2000\n", fs::read_to_string(&path).unwrap());
+
+        inputs.resolve(2022, 1).unwrap();
+        assert_eq!(1, server.request_count());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn wrong_session_is_an_error() {
+        let server = StandInServer::start(SESSION, vec![(2022, 1, "1000\n".to_string())]);
+        let cache_dir = temp_cache_dir("session");
+        let inputs = manager(&server, cache_dir.clone(), Some("stale-session"));
+
+        let err = inputs.resolve(2022, 1).unwrap_err();
+        assert!(format!("{:#}", err).contains("status 400"), "{:#}", err);
+        assert!(!inputs.cached_path(2022, 1).exists());
+    }
+
+    #[test]
+    fn missing_session_does_not_hit_the_server() {
+        let server = StandInServer::start(SESSION, Vec::new());
+        let inputs = manager(&server, temp_cache_dir("no-session"), None);
+
+        assert!(inputs.resolve(2022, 3).is_err());
+        assert_eq!(0, server.request_count());
+    }
+
+    #[test]
+    fn unknown_day_is_an_error() {
+        let server = StandInServer::start(SESSION, Vec::new());
+        let inputs = manager(&server, temp_cache_dir("unknown"), Some(SESSION));
+
+        let err = inputs.resolve(2022, 26).unwrap_err();
+        assert!(format!("{:#}", err).contains("status 404"), "{:#}", err);
+    }
+}
@@ -0,0 +1,408 @@
+use anyhow::{anyhow, Context};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+mod answers;
+mod days;
+mod inputs;
+mod memory;
+mod readme;
+mod scaffold;
+#[cfg(test)]
+mod stand_in_server;
+mod watch;
+
+const SUMMARY_WIDTH: usize = 3 + 32 + 32 + 12 + 12 + 12;
+const DAY_TABLE_WIDTH: usize = 8 + 32 + 12 + 12 + 9;
+
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+use answers::{AnswerRegistry, Outcome, Verification};
+use days::{DayRun, Part, PartRecord, PartRun, DAYS};
+use inputs::InputManager;
+
+#[derive(Debug, Parser)]
+#[command(about = "Run the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run a single day by number, or every day with `all`
+    Run {
+        day: DaySelection,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Use the input from the cache dir, fetching it if needed, instead of the checked in one
+        #[arg(long, conflicts_with = "input")]
+        cached: bool,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Download a day's input into the cache dir (needs AOC_SESSION) and print its path
+    Fetch {
+        day: u8,
+        #[arg(long, default_value_t = days::YEAR)]
+        year: u16,
+        /// Defaults to AOC_CACHE_DIR, or .aoc-cache in the workspace
+        #[arg(long)]
+        cache_dir: Option<PathBuf>,
+        /// Defaults to AOC_BASE_URL, or the Advent of Code site
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Check every day's answers against the expected answers file
+    Verify {
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Check every example in a day's ReadMe, or in every day's with `all`
+    Examples { day: DaySelection },
+    /// Rerun a day's tests and solver whenever its sources, examples or input change
+    Watch { day: u8 },
+    /// Create the crate for a new day from the templates and add it to the workspace
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Generate a synthetic input for a day, printed to stdout unless --output is given
+    Generate {
+        day: u8,
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Text,
+    /// One `{day, part, answer, duration_ns}` record per part, as a JSON array
+    Json,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "all" => Ok(DaySelection::All),
+            day => Ok(DaySelection::Day(
+                day.parse()
+                    .context(format!("{} is not a day number or 'all'", day))?,
+            )),
+        }
+    }
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate should live inside the workspace")
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            cached,
+            format,
+        } => {
+            let part = part.map(Part::try_from_number).transpose()?;
+            let inputs = cached.then(|| InputManager::from_env(workspace_root()));
+            match day {
+                DaySelection::Day(number) => {
+                    let input = match &inputs {
+                        Some(inputs) => Some(inputs.resolve(days::YEAR, number)?),
+                        None => input,
+                    };
+                    run_day(number, part, input, format)
+                }
+                DaySelection::All => {
+                    if input.is_some() {
+                        Err(anyhow!(
+                            "--input can only be used when running a single day"
+                        ))?
+                    }
+                    run_all(part, inputs.as_ref(), format)
+                }
+            }
+        }
+        Command::Verify { answers } => {
+            let answers_path = answers.unwrap_or_else(|| workspace_root().join("answers.toml"));
+            verify(&answers_path)
+        }
+        Command::Examples { day } => {
+            let days = match day {
+                DaySelection::Day(number) => vec![days::get_day(number)?],
+                DaySelection::All => DAYS.iter().collect(),
+            };
+            check_examples(&days)
+        }
+        Command::Watch { day } => watch::watch(days::get_day(day)?, workspace_root()),
+        Command::New { day } => {
+            let day_path = scaffold::new_day(workspace_root(), day)?;
+            println!("Created {}", day_path.display());
+            println!(
+                "Add it to DAYS in aoc/src/days.rs to run it with `aoc run {}`",
+                day
+            );
+            Ok(())
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => generate(day, size, seed, output),
+        Command::Fetch {
+            day,
+            year,
+            cache_dir,
+            base_url,
+        } => {
+            let mut inputs = InputManager::from_env(workspace_root());
+            if let Some(cache_dir) = cache_dir {
+                inputs.cache_dir = cache_dir;
+            }
+            if let Some(base_url) = base_url {
+                inputs.base_url = base_url;
+            }
+
+            println!("{}", inputs.resolve(year, day)?.display());
+            Ok(())
+        }
+    }
+}
+
+fn print_records(records: &[PartRecord]) -> anyhow::Result<()> {
+    let json = serde_json::to_string_pretty(records).context("serializing answers")?;
+    println!("{}", json);
+
+    Ok(())
+}
+
+fn run_day(
+    number: u8,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let day = days::get_day(number)?;
+    let input_path = input.unwrap_or_else(|| day.default_input_path(workspace_root()));
+
+    let day_run = day
+        .run(&input_path, part)
+        .context(format!("running day {}", number))?;
+
+    if let OutputFormat::Json = format {
+        return print_records(&day_run.records());
+    }
+
+    println!("Day {}", day_run.day);
+    println!(
+        "{:<8} | {:<32} | {:>12} | {:>12}",
+        "Step", "Answer", "Time", "Peak memory"
+    );
+    println!("{}", "-".repeat(DAY_TABLE_WIDTH));
+    println!(
+        "{:<8} | {:<32} | {:>12} | {:>12}",
+        "parse",
+        "",
+        format!("{:.2?}", day_run.parse_duration),
+        memory::format_bytes(day_run.parse_peak_bytes),
+    );
+    let part_runs = [
+        ("part one", day_run.part_one.as_ref()),
+        ("part two", day_run.part_two.as_ref()),
+    ];
+    for (label, part_run) in part_runs {
+        if let Some(part_run) = part_run {
+            print_part_row(label, part_run);
+        }
+    }
+    //Errors are too long for the answer column so they go under the table
+    for (label, part_run) in part_runs {
+        if let Some(PartRun {
+            answer: Err(err), ..
+        }) = part_run
+        {
+            println!("{} error: {:#}", label, err);
+        }
+    }
+
+    Ok(())
+}
+
+fn run_all(
+    part: Option<Part>,
+    inputs: Option<&InputManager>,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let day_runs = DAYS
+        .iter()
+        .map(|day| {
+            let input_path = match inputs {
+                Some(inputs) => inputs.resolve(days::YEAR, day.number),
+                None => Ok(day.default_input_path(workspace_root())),
+            };
+            let day_run = input_path.and_then(|input_path| day.run(&input_path, part));
+            (day.number, day_run)
+        })
+        .collect::<Vec<_>>();
+
+    if let OutputFormat::Json = format {
+        let records: Vec<PartRecord> = day_runs
+            .iter()
+            .flat_map(|(number, day_run)| match day_run {
+                Ok(day_run) => day_run.records(),
+                Err(err) => Part::selected(part)
+                    .into_iter()
+                    .map(|part| PartRecord::failed(*number, part, err))
+                    .collect(),
+            })
+            .collect();
+
+        return print_records(&records);
+    }
+
+    println!(
+        "{:>3} | {:<32} | {:<32} | {:>12} | {:>12}",
+        "Day", "Part one", "Part two", "Time", "Peak memory"
+    );
+    println!("{}", "-".repeat(SUMMARY_WIDTH));
+
+    let mut total_duration = Duration::ZERO;
+    for (number, day_run) in day_runs.iter() {
+        match day_run {
+            Ok(day_run) => {
+                total_duration += day_run.total_duration();
+                print_summary_row(day_run);
+            }
+            Err(err) => println!("Day {}: {:#}", number, err),
+        }
+    }
+
+    println!("{}", "-".repeat(SUMMARY_WIDTH));
+    println!("Total time: {:.2?}", total_duration);
+
+    Ok(())
+}
+
+fn print_part_row(label: &str, part_run: &PartRun) {
+    println!(
+        "{:<8} | {:<32} | {:>12} | {:>12}",
+        label,
+        summary_cell(&Some(part_run)),
+        format!("{:.2?}", part_run.duration),
+        memory::format_bytes(part_run.peak_bytes),
+    );
+}
+
+fn summary_cell(part_run: &Option<&PartRun>) -> String {
+    match part_run {
+        None => "-".to_string(),
+        Some(PartRun {
+            answer: Ok(answer), ..
+        }) => answer.to_string(),
+        Some(PartRun { answer: Err(_), .. }) => "error".to_string(),
+    }
+}
+
+fn print_summary_row(day_run: &DayRun) {
+    println!(
+        "{:>3} | {:<32} | {:<32} | {:>12} | {:>12}",
+        day_run.day,
+        summary_cell(&day_run.part_one.as_ref()),
+        summary_cell(&day_run.part_two.as_ref()),
+        format!("{:.2?}", day_run.total_duration()),
+        memory::format_bytes(day_run.peak_bytes()),
+    );
+}
+
+fn generate(day: u8, size: usize, seed: u64, output: Option<PathBuf>) -> anyhow::Result<()> {
+    let input = aoc_gen::generate(day, size, seed)?;
+
+    match output {
+        Some(path) => fs::write(&path, input).context(format!("writing {}", path.display()))?,
+        None => print!("{}", input),
+    }
+
+    Ok(())
+}
+
+fn verify(answers_path: &Path) -> anyhow::Result<()> {
+    let registry = AnswerRegistry::load(answers_path)?;
+    let verifications = answers::verify_all(&registry, workspace_root())?;
+
+    report_verifications(&verifications)
+}
+
+fn check_examples(days: &[&days::Day]) -> anyhow::Result<()> {
+    let mut verifications = Vec::new();
+    for day in days {
+        verifications.extend(readme::verify_examples(day, workspace_root())?);
+    }
+
+    report_verifications(&verifications)
+}
+
+fn report_verifications(verifications: &[Verification]) -> anyhow::Result<()> {
+    for verification in verifications.iter() {
+        print_verification(verification);
+    }
+
+    let failed = verifications
+        .iter()
+        .filter(|verification| !verification.passed())
+        .count();
+
+    println!("{} passed, {} failed", verifications.len() - failed, failed);
+
+    if failed > 0 {
+        Err(anyhow!("{} answers did not match", failed))?
+    }
+
+    Ok(())
+}
+
+fn print_verification(verification: &Verification) {
+    let part = match verification.part {
+        Part::One => "part one",
+        Part::Two => "part two",
+    };
+    let label = format!("day {} {} ({})", verification.day, part, verification.input);
+
+    match &verification.outcome {
+        Outcome::Pass => println!("PASS  {}", label),
+        Outcome::Fail { expected, actual } => {
+            println!("FAIL  {}", label);
+            println!("      - expected: {}", expected);
+            println!("      + actual:   {}", actual);
+        }
+        Outcome::Error(message) => println!("ERROR {}: {}", label, message),
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+//Counted per thread so allocations on other threads, like tests running in
+//parallel, don't show up in a measured peak. Signed since memory can be
+//freed on a different thread than the one that allocated it.
+thread_local! {
+    static ALLOCATED: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+/// Wraps the system allocator, keeping count of how many bytes each thread
+/// currently has allocated and the most it has had allocated at once.
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    //`try_with` as the thread locals are gone while a thread shuts down
+    let _ = ALLOCATED.try_with(|allocated| {
+        let now = allocated.get() + size as isize;
+        allocated.set(now);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+}
+
+fn record_dealloc(size: usize) {
+    let _ = ALLOCATED.try_with(|allocated| allocated.set(allocated.get() - size as isize));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                record_alloc(new_size - layout.size());
+            } else {
+                record_dealloc(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f`, returning the most bytes it had allocated at once on top of what
+/// was already allocated. Only meaningful while `CountingAllocator` is the
+/// global allocator, and only counts allocations made on the calling thread.
+pub fn track_peak<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let baseline = ALLOCATED.with(Cell::get);
+    PEAK.with(|peak| peak.set(baseline));
+
+    let result = f();
+
+    let peak = PEAK.with(Cell::get) - baseline;
+    (result, peak.max(0) as usize)
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format_bytes, track_peak};
+
+    #[test]
+    fn peak_includes_live_allocations() {
+        let (buffer, peak) = track_peak(|| vec![1u8; 1 << 20]);
+
+        assert_eq!(1 << 20, buffer.len());
+        assert!(peak >= 1 << 20, "peak was only {}", peak);
+    }
+
+    #[test]
+    fn other_threads_are_not_counted() {
+        let (_, peak) = track_peak(|| {
+            std::thread::spawn(|| vec![1u8; 1 << 20].len())
+                .join()
+                .unwrap()
+        });
+
+        assert!(peak < 1 << 20, "peak was {}", peak);
+    }
+
+    #[test]
+    fn bytes_are_shown_in_binary_units() {
+        assert_eq!("512 B", format_bytes(512));
+        assert_eq!("1.5 KiB", format_bytes(1536));
+        assert_eq!("3.0 MiB", format_bytes(3 << 20));
+    }
+}
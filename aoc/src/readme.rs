@@ -0,0 +1,348 @@
+//! Pulls example test cases out of each day's ReadMe.md puzzle text.
+//!
+//! An example input follows a line introducing it, e.g. "For example:" or
+//! "Here's a larger example:", either as a fenced code block or as the
+//! lines up to the next one that reads like a sentence. Each part ends with
+//! its question, and the line before that states the answer for the most
+//! recent example, as its last number or all-caps word outside brackets. A
+//! line like `input: ... answer` is an example of its own, used for short
+//! inputs listed one per line.
+
+use anyhow::{anyhow, Context};
+use std::{fs, path::Path};
+
+use crate::{
+    answers::{self, Verification},
+    days::{Day, Part},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleCase {
+    pub part: Part,
+    /// Line of the ReadMe the answer was found on
+    pub line: usize,
+    pub input: String,
+    pub answer: String,
+}
+
+fn introduces_example(line: &str) -> bool {
+    let line = line.trim().to_lowercase();
+
+    //Lines like "this example list ... look like this:" introduce drawings
+    line.ends_with(':') && (line.contains("for example") || line.contains("larger example"))
+}
+
+fn reads_like_a_sentence(line: &str) -> bool {
+    line.trim_end().ends_with(['.', ':', '?', '!'])
+}
+
+fn is_fence(line: &str) -> bool {
+    line.starts_with("```")
+}
+
+fn without_brackets(line: &str) -> String {
+    let mut depth = 0usize;
+    line.chars()
+        .filter(|&c| match c {
+            '(' => {
+                depth += 1;
+                false
+            }
+            ')' => {
+                depth = depth.saturating_sub(1);
+                false
+            }
+            _ => depth == 0,
+        })
+        .collect()
+}
+
+/// The last number or all-caps word, e.g. the `CMZ` of "give the Elves the
+/// message CMZ."
+fn stated_answer(line: &str) -> Option<String> {
+    without_brackets(line)
+        .split_whitespace()
+        .rev()
+        .map(|word| word.trim_matches(|c: char| c.is_ascii_punctuation() && c != '-'))
+        .find(|word| {
+            word.parse::<i64>().is_ok()
+                || (word.len() > 1 && word.chars().all(|c| c.is_ascii_uppercase()))
+        })
+        .map(str::to_string)
+}
+
+fn inline_example(line: &str) -> Option<(String, String)> {
+    let (input, rest) = line.split_once(": ")?;
+    if input.is_empty() || input.contains(char::is_whitespace) {
+        return None;
+    }
+    let answer = stated_answer(rest).filter(|answer| rest.trim_end().ends_with(answer.as_str()))?;
+
+    Some((format!("{}\n", input), answer))
+}
+
+/// A line outside of any example or code block
+struct Prose<'a> {
+    index: usize,
+    part: Part,
+    text: &'a str,
+    /// The most recent example before this line
+    example: Option<usize>,
+}
+
+pub fn extract_examples(readme: &str) -> anyhow::Result<Vec<ExampleCase>> {
+    let lines: Vec<&str> = readme.lines().collect();
+    let mut examples: Vec<String> = Vec::new();
+    let mut prose: Vec<Prose> = Vec::new();
+    let mut part = Part::One;
+    let mut introduced = false;
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if line.trim() == "--- Part Two ---" {
+            part = Part::Two;
+            introduced = false;
+            continue;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if is_fence(line) {
+            let body_start = i;
+            while i < lines.len() && !is_fence(lines[i]) {
+                i += 1;
+            }
+            if introduced {
+                examples.push(
+                    lines[body_start..i]
+                        .iter()
+                        .map(|l| format!("{}\n", l))
+                        .collect(),
+                );
+            }
+            i += 1;
+            introduced = false;
+            continue;
+        }
+
+        if introduced && !reads_like_a_sentence(line) {
+            let body_start = i - 1;
+            while i < lines.len() && !reads_like_a_sentence(lines[i]) && !is_fence(lines[i]) {
+                i += 1;
+            }
+            let mut body = &lines[body_start..i];
+            while let [rest @ .., last] = body {
+                if !last.trim().is_empty() {
+                    break;
+                }
+                body = rest;
+            }
+            examples.push(body.iter().map(|l| format!("{}\n", l)).collect());
+            introduced = false;
+            continue;
+        }
+
+        introduced = introduces_example(line);
+        prose.push(Prose {
+            index: i - 1,
+            part,
+            text: line,
+            example: examples.len().checked_sub(1),
+        });
+    }
+
+    let is_question = |n: usize| {
+        prose
+            .get(n + 1)
+            .is_none_or(|next| next.part != prose[n].part)
+    };
+
+    let mut cases = Vec::new();
+    for (n, line) in prose.iter().enumerate() {
+        let case = |input: String, answer: String| ExampleCase {
+            part: line.part,
+            line: line.index + 1,
+            input,
+            answer,
+        };
+
+        if let Some((input, answer)) = inline_example(line.text) {
+            cases.push(case(input, answer));
+            continue;
+        }
+
+        //The answer is stated on the line before the part's question
+        let answers_question =
+            prose.get(n + 1).is_some_and(|next| next.part == line.part) && is_question(n + 1);
+        if !answers_question {
+            continue;
+        }
+
+        if let Some(answer) = stated_answer(line.text) {
+            let input = line
+                .example
+                .map(|example| examples[example].clone())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Answer {} on line {} has no example",
+                        answer,
+                        line.index + 1
+                    )
+                })?;
+            cases.push(case(input, answer));
+        }
+    }
+
+    Ok(cases)
+}
+
+pub fn verify_examples(day: &Day, workspace_root: &Path) -> anyhow::Result<Vec<Verification>> {
+    let readme_path = day.readme_path(workspace_root);
+    let readme =
+        fs::read_to_string(&readme_path).context(format!("reading {}", readme_path.display()))?;
+    let cases =
+        extract_examples(&readme).context(format!("extracting {}", readme_path.display()))?;
+    if cases.is_empty() {
+        Err(anyhow!("No examples found in {}", readme_path.display()))?
+    }
+
+    let verifications = cases
+        .into_iter()
+        .map(|case| {
+            let input = format!("{}:{}", day.readme_file, case.line);
+            let outcome = match day.run_reader(&mut case.input.as_bytes(), Some(case.part)) {
+                Err(err) => answers::Outcome::Error(format!("{:#}", err)),
+                Ok(day_run) => {
+                    let part_run = match case.part {
+                        Part::One => day_run.part_one.as_ref(),
+                        Part::Two => day_run.part_two.as_ref(),
+                    };
+                    answers::verify_part(&case.answer, part_run)
+                }
+            };
+
+            Verification {
+                day: day.number,
+                input,
+                part: case.part,
+                outcome,
+            }
+        })
+        .collect();
+
+    Ok(verifications)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{extract_examples, verify_examples, ExampleCase};
+    use crate::{
+        days::{self, Part},
+        workspace_root,
+    };
+
+    #[test]
+    fn answers_follow_the_latest_example() {
+        let readme = [
+            "For example:",
+            "",
+            "1",
+            "",
+            "2",
+            "This list has two numbers.",
+            "",
+            "The answer is 3 (1 + 2).",
+            "",
+            "What is the answer?",
+            "",
+            "--- Part Two ---",
+            "In the example above, it is 6.",
+            "Here's a larger example:",
+            "```",
+            "10",
+            "```",
+            "Now it is 60.",
+            "What is it now?",
+        ]
+        .join("\n");
+
+        let cases = extract_examples(&readme).unwrap();
+
+        let expected = [
+            (Part::One, 8, "1\n\n2\n", "3"),
+            (Part::Two, 18, "10\n", "60"),
+        ]
+        .map(|(part, line, input, answer)| ExampleCase {
+            part,
+            line,
+            input: input.to_string(),
+            answer: answer.to_string(),
+        });
+        assert_eq!(expected.to_vec(), cases);
+    }
+
+    #[test]
+    fn blocks_after_other_text_are_not_examples() {
+        let readme = "This looks like this:\n```\n#..\n```\nThere are 2.\n\nHow many?";
+
+        assert!(extract_examples(readme).is_err());
+    }
+
+    #[test]
+    fn answers_can_be_words() {
+        let readme = "For example:\n\nabc\nThe message is XYZ.\nWhat is the message?";
+
+        let cases = extract_examples(readme).unwrap();
+
+        assert_eq!(1, cases.len());
+        assert_eq!("XYZ", cases[0].answer);
+    }
+
+    #[test]
+    fn inline_examples_use_their_own_input() {
+        let readme = "Here are a few more examples:\n\nabcd: first marker after character 4";
+
+        let cases = extract_examples(readme).unwrap();
+
+        assert_eq!(1, cases.len());
+        assert_eq!("abcd\n", cases[0].input);
+        assert_eq!("4", cases[0].answer);
+    }
+
+    macro_rules! readme_example_tests {
+        ($($(#[$attr:meta])* $name:ident: $day:expr,)*) => {
+            $(
+                #[test]
+                $(#[$attr])*
+                fn $name() {
+                    let day = days::get_day($day).unwrap();
+                    let verifications = verify_examples(day, workspace_root())
+                        .unwrap_or_else(|err| panic!("{:#}", err));
+
+                    let failures: Vec<_> = verifications
+                        .iter()
+                        .filter(|verification| !verification.passed())
+                        .collect();
+                    assert!(failures.is_empty(), "failed examples {:?}", failures);
+                }
+            )*
+        };
+    }
+
+    readme_example_tests! {
+        day_01_readme_examples: 1,
+        day_02_readme_examples: 2,
+        day_03_readme_examples: 3,
+        day_04_readme_examples: 4,
+        day_05_readme_examples: 5,
+        day_06_readme_examples: 6,
+        day_07_readme_examples: 7,
+        day_08_readme_examples: 8,
+        day_09_readme_examples: 9,
+        day_10_readme_examples: 10,
+    }
+}
@@ -0,0 +1,170 @@
+use anyhow::{anyhow, Context};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATES: [(&str, &str); 4] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    (
+        "src/input_parser.rs",
+        include_str!("../templates/input_parser.rs.tmpl"),
+    ),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+];
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{day_padded}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds `dir` to the `members` list of the workspace manifest, keeping the
+/// list's formatting. Does nothing if it is already a member.
+fn add_workspace_member(manifest: &str, dir: &str) -> anyhow::Result<String> {
+    let members_start = manifest
+        .find("members = [")
+        .ok_or_else(|| anyhow!("No workspace members list in Cargo.toml"))?;
+    let list_start = members_start + "members = [".len();
+    let list_end = list_start
+        + manifest[list_start..]
+            .find(']')
+            .ok_or_else(|| anyhow!("Workspace members list is not closed"))?;
+
+    let mut members: Vec<&str> = manifest[list_start..list_end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect();
+
+    if members.contains(&dir) {
+        return Ok(manifest.to_string());
+    }
+    members.push(dir);
+    members.sort();
+
+    let members_list: Vec<String> = members
+        .iter()
+        .map(|member| format!("  \"{}\"", member))
+        .collect();
+
+    Ok(format!(
+        "{}\n{}\n{}",
+        &manifest[..list_start],
+        members_list.join(",\n"),
+        &manifest[list_end..]
+    ))
+}
+
+fn write_day(day_path: &Path, day: u8) -> anyhow::Result<()> {
+    fs::create_dir_all(day_path.join("src")).context(format!("creating {}", day_path.display()))?;
+
+    for (file, template) in TEMPLATES {
+        let path = day_path.join(file);
+        fs::write(&path, render(template, day)).context(format!("writing {}", path.display()))?;
+    }
+    fs::write(day_path.join("example.txt"), "").context("writing example.txt")?;
+
+    Ok(())
+}
+
+/// Creates the `dNN` crate for `day` from the templates, with an empty
+/// `example.txt` for the tests, and registers it with the workspace. The
+/// crate is removed again if it can't be registered.
+pub fn new_day(workspace_root: &Path, day: u8) -> anyhow::Result<PathBuf> {
+    let dir = format!("d{:02}", day);
+    let day_path = workspace_root.join(&dir);
+    if day_path.exists() {
+        Err(anyhow!("{} already exists", day_path.display()))?
+    }
+
+    let manifest_path = workspace_root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .context(format!("reading {}", manifest_path.display()))?;
+    let manifest = add_workspace_member(&manifest, &dir)?;
+
+    let written = write_day(&day_path, day).and_then(|_| {
+        fs::write(&manifest_path, manifest).context(format!("writing {}", manifest_path.display()))
+    });
+    if let Err(err) = written {
+        let _ = fs::remove_dir_all(&day_path);
+        return Err(err);
+    }
+
+    Ok(day_path)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{add_workspace_member, new_day, render};
+
+    const MANIFEST: &str =
+        "[workspace]\nresolver = \"2\"\n\nmembers = [\n  \"aoc\",\n  \"d01\",\n  \"d10\"\n]  \n";
+
+    #[test]
+    fn member_is_added_in_order() {
+        let manifest = add_workspace_member(MANIFEST, "d02").unwrap();
+
+        assert_eq!(
+            "[workspace]\nresolver = \"2\"\n\nmembers = [\n  \"aoc\",\n  \"d01\",\n  \"d02\",\n  \"d10\"\n]  \n",
+            manifest
+        );
+        assert_eq!(manifest, add_workspace_member(&manifest, "d02").unwrap());
+    }
+
+    #[test]
+    fn templates_use_the_repo_naming() {
+        let rendered = render("d{{day}} Day{{day_padded}} {{day}}", 7);
+
+        assert_eq!("d7 Day07 7", rendered);
+    }
+
+    #[test]
+    fn creates_crate_and_registers_it() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+        let day_path = new_day(&root, 11).unwrap();
+
+        assert_eq!(root.join("d11"), day_path);
+        for file in [
+            "Cargo.toml",
+            "example.txt",
+            "src/lib.rs",
+            "src/input_parser.rs",
+            "src/main.rs",
+        ] {
+            assert!(day_path.join(file).exists(), "missing {}", file);
+        }
+        let lib = fs::read_to_string(day_path.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day11"));
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"d11\""));
+
+        assert!(new_day(&root, 11).is_err(), "should not overwrite a day");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn bad_manifest_leaves_no_crate_behind() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-bad-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\n").unwrap();
+
+        assert!(new_day(&root, 11).is_err());
+        assert!(!root.join("d11").exists());
+
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        assert!(new_day(&root, 11).is_ok(), "should be able to retry");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+};
+use tiny_http::{Response, Server};
+
+/// A local stand-in for the puzzle site's input endpoint so the fetch path
+/// can be tested without network access. Answers
+/// `GET /{year}/day/{day}/input` for the inputs it was started with, and only
+/// when the request carries the expected session cookie.
+pub struct StandInServer {
+    server: Arc<Server>,
+    requests: Arc<AtomicUsize>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl StandInServer {
+    pub fn start(session: &str, inputs: Vec<(u16, u8, String)>) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("binding stand-in server"));
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let inputs: HashMap<String, String> = inputs
+            .into_iter()
+            .map(|(year, day, input)| (format!("/{}/day/{}/input", year, day), input))
+            .collect();
+        let expected_cookie = format!("session={}", session);
+
+        let handle = {
+            let server = Arc::clone(&server);
+            let requests = Arc::clone(&requests);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    requests.fetch_add(1, Ordering::SeqCst);
+
+                    let has_session = request.headers().iter().any(|header| {
+                        header.field.equiv("Cookie") && header.value.as_str() == expected_cookie
+                    });
+
+                    let response = match inputs.get(request.url()) {
+                        _ if !has_session => {
+                            Response::from_string("Missing session").with_status_code(400)
+                        }
+                        Some(input) => Response::from_string(input.clone()),
+                        None => Response::from_string("Not found").with_status_code(404),
+                    };
+
+                    let _ = request.respond(response);
+                }
+            })
+        };
+
+        StandInServer {
+            server,
+            requests,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn base_url(&self) -> String {
+        let addr = self
+            .server
+            .server_addr()
+            .to_ip()
+            .expect("stand-in server listens on tcp");
+        format!("http://{}", addr)
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for StandInServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
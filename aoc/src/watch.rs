@@ -0,0 +1,318 @@
+use anyhow::{anyhow, Context};
+use notify::{RecursiveMode, Watcher};
+use std::{
+    env,
+    path::Path,
+    process::{Command, Output},
+    sync::mpsc,
+    time::Duration,
+};
+
+use crate::days::{Day, PartRecord};
+
+/// How long to wait for more changes before rerunning, so one save that
+/// touches several files only triggers a single run
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+}
+
+struct CheckRun {
+    tests: Result<TestSummary, String>,
+    answers: Result<Vec<PartRecord>, String>,
+}
+
+fn is_watched(day: &Day, day_dir: &Path, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(day_dir) else {
+        return false;
+    };
+    if relative.starts_with("src") {
+        return true;
+    }
+
+    let file_name = relative.to_string_lossy();
+    file_name == day.input_file || (file_name.starts_with("example") && file_name.ends_with(".txt"))
+}
+
+fn cargo(workspace_root: &Path, args: &[&str]) -> anyhow::Result<Output> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    Command::new(&cargo)
+        .args(args)
+        .current_dir(workspace_root)
+        .output()
+        .context(format!("running {} {}", cargo, args.join(" ")))
+}
+
+/// Adds up the `test result:` lines cargo prints for each test binary
+pub fn parse_test_summary(output: &str) -> Option<TestSummary> {
+    let mut summary: Option<TestSummary> = None;
+
+    for line in output.lines() {
+        let Some((_, counts)) = line.split_once("test result: ") else {
+            continue;
+        };
+        let summary = summary.get_or_insert_with(TestSummary::default);
+
+        for count in counts.split(['.', ';']) {
+            let mut words = count.split_whitespace();
+            let (Some(number), Some(label)) = (words.next(), words.next()) else {
+                continue;
+            };
+            let Ok(number) = number.parse::<usize>() else {
+                continue;
+            };
+            match label {
+                "passed" => summary.passed += number,
+                "failed" => summary.failed += number,
+                "ignored" => summary.ignored += number,
+                _ => {}
+            }
+        }
+    }
+
+    summary
+}
+
+fn first_errors(output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let errors: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with("error"))
+        .take(3)
+        .collect();
+
+    if errors.is_empty() {
+        stderr.lines().last().unwrap_or("no output").to_string()
+    } else {
+        errors.join("\n")
+    }
+}
+
+fn run_tests(day: &Day, workspace_root: &Path) -> Result<TestSummary, String> {
+    let mut summary = TestSummary::default();
+    //Day crates are named d1, d2... while their dirs are zero padded
+    let package = format!("d{}", day.number);
+    let readme_test = format!("day_{:02}_readme_examples", day.number);
+
+    for args in [
+        vec!["test", "-p", &package],
+        vec!["test", "-p", "aoc", &readme_test],
+    ] {
+        let output = cargo(workspace_root, &args).map_err(|err| format!("{:#}", err))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        match parse_test_summary(&stdout) {
+            Some(test_summary) => {
+                summary.passed += test_summary.passed;
+                summary.failed += test_summary.failed;
+                summary.ignored += test_summary.ignored;
+            }
+            None => return Err(first_errors(&output)),
+        }
+    }
+
+    Ok(summary)
+}
+
+fn run_solver(day: &Day, workspace_root: &Path) -> Result<Vec<PartRecord>, String> {
+    let day_number = day.number.to_string();
+    let output = cargo(
+        workspace_root,
+        &[
+            "run",
+            "-q",
+            "-p",
+            "aoc",
+            "--",
+            "run",
+            &day_number,
+            "--format",
+            "json",
+        ],
+    )
+    .map_err(|err| format!("{:#}", err))?;
+
+    if !output.status.success() {
+        return Err(first_errors(&output));
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|err| format!("reading answers: {}", err))
+}
+
+fn answer_text(record: &PartRecord) -> String {
+    match (&record.answer, &record.error) {
+        (Some(serde_json::Value::String(answer)), _) => answer.clone(),
+        (Some(answer), _) => answer.to_string(),
+        (None, Some(error)) => format!("error: {}", error),
+        (None, None) => "-".to_string(),
+    }
+}
+
+/// One line per part comparing its answer with the previous run's
+pub fn diff_answers(previous: Option<&[PartRecord]>, current: &[PartRecord]) -> Vec<String> {
+    current
+        .iter()
+        .map(|record| {
+            let label = if record.part == 1 {
+                "part one"
+            } else {
+                "part two"
+            };
+            let answer = answer_text(record);
+            let previous_answer = previous
+                .and_then(|previous| previous.iter().find(|prev| prev.part == record.part))
+                .map(answer_text);
+
+            match previous_answer {
+                Some(previous_answer) if previous_answer == answer => {
+                    format!("  {}: {} (unchanged)", label, answer)
+                }
+                Some(previous_answer) => format!("  {}: {} -> {}", label, previous_answer, answer),
+                None => format!("  {}: {}", label, answer),
+            }
+        })
+        .collect()
+}
+
+fn report(check_run: &CheckRun, previous: Option<&[PartRecord]>) {
+    match &check_run.tests {
+        Ok(summary) if summary.failed == 0 => println!(
+            "PASS  {} passed, {} ignored",
+            summary.passed, summary.ignored
+        ),
+        Ok(summary) => println!(
+            "FAIL  {} passed, {} failed, {} ignored",
+            summary.passed, summary.failed, summary.ignored
+        ),
+        Err(message) => println!("ERROR tests did not run: {}", message),
+    }
+
+    match &check_run.answers {
+        Ok(answers) => {
+            for line in diff_answers(previous, answers) {
+                println!("{}", line);
+            }
+        }
+        Err(message) => println!("ERROR solver did not run: {}", message),
+    }
+}
+
+/// Reruns the day's tests and solver every time its sources, examples or
+/// input change, until interrupted
+pub fn watch(day: &Day, workspace_root: &Path) -> anyhow::Result<()> {
+    let day_dir = workspace_root
+        .join(day.dir)
+        .canonicalize()
+        .context(format!("finding {}", day.dir))?;
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).context("starting file watcher")?;
+    watcher
+        .watch(&day_dir, RecursiveMode::Recursive)
+        .context(format!("watching {}", day_dir.display()))?;
+
+    let mut previous: Option<Vec<PartRecord>> = None;
+    loop {
+        println!("== Day {} ==", day.number);
+        let check_run = CheckRun {
+            tests: run_tests(day, workspace_root),
+            answers: run_solver(day, workspace_root),
+        };
+        report(&check_run, previous.as_deref());
+        if let Ok(answers) = check_run.answers {
+            previous = Some(answers);
+        }
+
+        println!("Watching {} for changes...", day_dir.display());
+        loop {
+            let event = receiver
+                .recv()
+                .map_err(|_| anyhow!("file watcher stopped"))?
+                .context("watching files")?;
+
+            if event
+                .paths
+                .iter()
+                .any(|path| is_watched(day, &day_dir, path))
+            {
+                break;
+            }
+        }
+        //Drain the rest of the burst of events from the same save
+        while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{diff_answers, is_watched, parse_test_summary, TestSummary};
+    use crate::days::{self, PartRecord};
+
+    #[test]
+    fn sums_every_test_binary() {
+        let output = [
+            "running 3 tests",
+            "test result: ok. 3 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.01s",
+            "test result: FAILED. 1 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            Some(TestSummary {
+                passed: 4,
+                failed: 2,
+                ignored: 1
+            }),
+            parse_test_summary(&output)
+        );
+        assert_eq!(None, parse_test_summary("error: could not compile"));
+    }
+
+    fn record(part: u8, answer: serde_json::Value) -> PartRecord {
+        PartRecord {
+            day: 1,
+            part,
+            answer: Some(answer),
+            error: None,
+            duration_ns: 0,
+            peak_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn answers_are_diffed_against_the_last_run() {
+        let previous = [record(1, 24000.into()), record(2, 41000.into())];
+        let current = [record(1, 24000.into()), record(2, 45000.into())];
+
+        assert_eq!(
+            vec![
+                "  part one: 24000 (unchanged)",
+                "  part two: 41000 -> 45000"
+            ],
+            diff_answers(Some(&previous), &current)
+        );
+        assert_eq!(
+            vec!["  part one: CMZ"],
+            diff_answers(None, &[record(1, "CMZ".into())])
+        );
+    }
+
+    #[test]
+    fn only_sources_examples_and_input_are_watched() {
+        let day = days::get_day(10).unwrap();
+        let day_dir = Path::new("/repo/d10");
+
+        assert!(is_watched(day, day_dir, &day_dir.join("src/lib.rs")));
+        assert!(is_watched(day, day_dir, &day_dir.join("example.txt")));
+        assert!(is_watched(day, day_dir, &day_dir.join("input.txt")));
+        assert!(!is_watched(day, day_dir, &day_dir.join("ReadMe.md")));
+        assert!(!is_watched(day, day_dir, Path::new("/repo/d09/src/lib.rs")));
+    }
+}
@@ -1,31 +1,132 @@
-use anyhow::Context;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use aoc_core::{groups::Groups, LineError};
+use std::{fmt, io::BufRead, str::FromStr};
 
-pub type ItemCalories = i32;
+use crate::{stats::CalorieStats, top_calories};
+
+pub type ItemCalories = u64;
 pub type ElfItems = Vec<ItemCalories>;
-pub type Elfs = Vec<ElfItems>;
-
-pub fn get_elfs_from_file(file: &File) -> anyhow::Result<Elfs> {
-    let reader = BufReader::new(file);
-    let mut elfs: Elfs = Vec::new();
-    let mut current_elf: ElfItems = Vec::new();
-    for line in reader.lines() {
-        let line = line.context("reading line to string")?;
-        let content = line.trim();
-        match content {
-            "" => {
-                elfs.push(current_elf.clone());
-                current_elf = Vec::new();
-            }
-            value => {
-                let numeric_val: ItemCalories = value.parse().context("parsing value")?;
-                current_elf.push(numeric_val);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalorieError {
+    /// The items of the elf at this 0-based index add up to more than fits in `ItemCalories`
+    ElfTotalOverflow { elf: usize },
+    /// Adding up several elves' totals overflowed
+    SumOverflow,
+}
+
+impl fmt::Display for CalorieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalorieError::ElfTotalOverflow { elf } => write!(
+                f,
+                "calories carried by elf {} overflow {}",
+                elf + 1,
+                ItemCalories::MAX
+            ),
+            CalorieError::SumOverflow => {
+                write!(f, "sum of elf totals overflows {}", ItemCalories::MAX)
             }
         }
     }
+}
+
+impl std::error::Error for CalorieError {}
+
+/// Sum of `calories`, or `None` if it doesn't fit in `ItemCalories`
+pub fn checked_sum<'a>(
+    calories: impl IntoIterator<Item = &'a ItemCalories>,
+) -> Option<ItemCalories> {
+    calories
+        .into_iter()
+        .try_fold(0, |total: ItemCalories, &cals| total.checked_add(cals))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elfs(pub Vec<ElfItems>);
+
+impl Elfs {
+    pub fn totals(&self) -> impl Iterator<Item = Result<ItemCalories, CalorieError>> + '_ {
+        self.0.iter().enumerate().map(|(elf, elf_cals)| {
+            checked_sum(elf_cals).ok_or(CalorieError::ElfTotalOverflow { elf })
+        })
+    }
+
+    /// The `k` largest elf totals, largest first
+    pub fn top_n(&self, k: usize) -> anyhow::Result<Vec<ItemCalories>> {
+        top_calories::top_n(self.totals().map(|total| Ok(total?)), k)
+    }
+
+    pub fn stats(&self) -> anyhow::Result<CalorieStats> {
+        CalorieStats::from_elfs(self)
+    }
+}
+
+impl FromStr for Elfs {
+    type Err = anyhow::Error;
 
-    Ok(elfs)
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        get_elfs_from_reader(input.as_bytes())
+    }
+}
+
+pub(crate) fn parse_calories(line: &str) -> Result<ItemCalories, LineError> {
+    let value = line.trim();
+    value
+        .parse()
+        .map_err(|err| LineError::at_slice(line, value, format!("invalid calories: {}", err)))
+}
+
+pub fn get_elfs_from_reader(reader: impl BufRead) -> anyhow::Result<Elfs> {
+    let elfs = Groups::new(reader)
+        .map(|group| Ok(group?.parse_lines(parse_calories)?))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(Elfs(elfs))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{CalorieError, Elfs, ItemCalories};
+
+    #[test]
+    fn keeps_last_elf_without_trailing_blank_line() {
+        let elfs = Elfs::from_str("1000\n2000\n\n3000").unwrap();
+
+        assert_eq!(Elfs(vec![vec![1000, 2000], vec![3000]]), elfs);
+    }
+
+    #[test]
+    fn tolerates_crlf_and_repeated_blank_lines() {
+        let elfs = Elfs::from_str("1000\r\n2000\r\n\r\n\r\n3000\r\n\r\n").unwrap();
+
+        assert_eq!(Elfs(vec![vec![1000, 2000], vec![3000]]), elfs);
+    }
+
+    #[test]
+    fn bad_calories_report_their_line() {
+        let err = Elfs::from_str("1000\n\n20x0").unwrap_err();
+
+        assert_eq!(
+            3,
+            err.downcast_ref::<aoc_core::ParseError>()
+                .unwrap()
+                .line_number
+        );
+    }
+
+    #[test]
+    fn overflowing_elf_total_is_an_error() {
+        let elfs = Elfs(vec![vec![1], vec![ItemCalories::MAX, 1]]);
+
+        assert_eq!(
+            vec![Ok(1), Err(CalorieError::ElfTotalOverflow { elf: 1 })],
+            elfs.totals().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(&CalorieError::ElfTotalOverflow { elf: 1 }),
+            elfs.top_n(1).unwrap_err().downcast_ref()
+        );
+    }
 }
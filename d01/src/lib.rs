@@ -0,0 +1,35 @@
+use anyhow::Context;
+use aoc_core::Solution;
+use std::io::BufRead;
+
+pub mod input_parser;
+pub mod stats;
+pub mod top_calories;
+
+use input_parser::{CalorieError, Elfs, ItemCalories};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Elfs;
+    type PartOne = ItemCalories;
+    type PartTwo = ItemCalories;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Elfs> {
+        input_parser::get_elfs_from_reader(reader)
+    }
+
+    fn part_one(elfs: &Elfs) -> anyhow::Result<ItemCalories> {
+        let highest = elfs.top_n(1).context("getting the max")?;
+
+        Ok(highest[0])
+    }
+
+    fn part_two(elfs: &Elfs) -> anyhow::Result<ItemCalories> {
+        let top_three = elfs.top_n(3).context("getting the top three")?;
+
+        Ok(input_parser::checked_sum(&top_three).ok_or(CalorieError::SumOverflow)?)
+    }
+}
@@ -1,41 +1,40 @@
 use anyhow::Context;
-use std::fs::File;
-
-mod input_parser;
-fn main() -> anyhow::Result<()> {
-    let input_file = File::open("./my_input.txt").context("opening file")?;
-
-    let parsed = input_parser::get_elfs_from_file(&input_file).context("parsing file")?;
-
-    let mut summed: Vec<i32> = parsed
-        .iter()
-        .map(|elf_cals| elf_cals.iter().cloned().sum())
-        .collect();
-
-    //part one get the highest
-    let highest = summed.iter().max().context("getting the max")?;
-
-    println!("highest elf cals {:?}", highest);
-
-    //part 2
-    let mut top_three = Vec::new();
-
-    while top_three.len() < 3 {
-        let (i, _) = summed
-            .iter()
-            .enumerate()
-            .max_by_key(|(_, &x)| x)
-            .expect("expected max");
-
-        let max = summed.remove(i);
-        top_three.push(max);
+use d1::Day01;
+use std::{env, path::Path};
+
+const HISTOGRAM_BINS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 50;
+
+fn print_stats(path: &Path) -> anyhow::Result<()> {
+    let elfs = aoc_core::parse_file::<Day01>(path)?;
+    let stats = elfs.stats().context("getting calorie stats")?;
+
+    println!("Elves: {}", stats.count);
+    println!("Mean: {:.1}", stats.mean);
+    println!("Median: {}", stats.median);
+    println!("Min: {}", stats.min);
+    println!("Max: {}", stats.max);
+    for percent in [10.0, 25.0, 75.0, 90.0, 99.0] {
+        println!("p{}: {}", percent, stats.percentile(percent)?);
     }
+    let most_items = stats.item_counts.iter().max().unwrap_or(&0);
+    println!("Most items carried by one elf: {}", most_items);
 
-    println!("top 3 = {:?}", top_three);
+    println!();
+    print!(
+        "{}",
+        d1::stats::render_histogram(&stats.histogram(HISTOGRAM_BINS), HISTOGRAM_WIDTH)
+    );
 
-    let top_three_summed: i32 = top_three.iter().sum();
+    Ok(())
+}
 
-    println!("total = {:?}", top_three_summed);
+fn main() -> anyhow::Result<()> {
+    let input_path = Path::new("./my_input.txt");
 
-    Ok(())
+    if env::args().any(|arg| arg == "--stats") {
+        print_stats(input_path)
+    } else {
+        aoc_core::print_answers::<Day01>(input_path)
+    }
 }
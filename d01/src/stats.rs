@@ -0,0 +1,193 @@
+use anyhow::anyhow;
+use std::fmt::Write;
+
+use crate::input_parser::{Elfs, ItemCalories};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistogramBin {
+    /// Inclusive range of totals counted in this bin
+    pub start: ItemCalories,
+    pub end: ItemCalories,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieStats {
+    pub count: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub min: ItemCalories,
+    pub max: ItemCalories,
+    /// Number of items each elf carries, in input order
+    pub item_counts: Vec<usize>,
+    sorted_totals: Vec<ItemCalories>,
+}
+
+impl CalorieStats {
+    pub fn from_elfs(elfs: &Elfs) -> anyhow::Result<Self> {
+        let mut sorted_totals = elfs.totals().collect::<Result<Vec<_>, _>>()?;
+        sorted_totals.sort_unstable();
+
+        let count = sorted_totals.len();
+        let (&min, &max) = sorted_totals
+            .first()
+            .zip(sorted_totals.last())
+            .ok_or_else(|| anyhow!("No elves to get stats for"))?;
+
+        let mean = sorted_totals.iter().map(|&total| total as f64).sum::<f64>() / count as f64;
+        let median = if count.is_multiple_of(2) {
+            (sorted_totals[count / 2 - 1] as f64 + sorted_totals[count / 2] as f64) / 2.0
+        } else {
+            sorted_totals[count / 2] as f64
+        };
+
+        Ok(CalorieStats {
+            count,
+            mean,
+            median,
+            min,
+            max,
+            item_counts: elfs.0.iter().map(|elf| elf.len()).collect(),
+            sorted_totals,
+        })
+    }
+
+    /// Nearest-rank percentile of the elf totals, `percent` from 0 to 100
+    pub fn percentile(&self, percent: f64) -> anyhow::Result<ItemCalories> {
+        if !(0.0..=100.0).contains(&percent) {
+            Err(anyhow!("{} is not a percentile between 0 and 100", percent))?
+        }
+
+        let rank = (percent / 100.0 * self.count as f64).ceil() as usize;
+        Ok(self.sorted_totals[rank.clamp(1, self.count) - 1])
+    }
+
+    /// Elf totals counted into `no_bins` equally sized bins from min to max
+    pub fn histogram(&self, no_bins: usize) -> Vec<HistogramBin> {
+        //Widened so the span of a full range of totals can't overflow
+        let no_bins = no_bins.max(1) as u128;
+        let (min, max) = (self.min as u128, self.max as u128);
+        let bin_width = (max - min + 1).div_ceil(no_bins);
+
+        let mut bins: Vec<HistogramBin> = (0..no_bins)
+            .map(|i| min + i * bin_width)
+            .take_while(|&start| start <= max)
+            .map(|start| HistogramBin {
+                start: start as ItemCalories,
+                end: (start + bin_width - 1).min(max) as ItemCalories,
+                count: 0,
+            })
+            .collect();
+
+        for &total in self.sorted_totals.iter() {
+            let i = ((total as u128 - min) / bin_width) as usize;
+            bins[i].count += 1;
+        }
+
+        bins
+    }
+}
+
+/// One row per bin with a bar of `#` scaled so the fullest bin is `width` long
+pub fn render_histogram(bins: &[HistogramBin], width: usize) -> String {
+    let most = bins.iter().map(|bin| bin.count).max().unwrap_or(0).max(1);
+    let label_width = bins
+        .iter()
+        .map(|bin| format!("{}-{}", bin.start, bin.end).len())
+        .max()
+        .unwrap_or(0);
+
+    let mut rendered = String::new();
+    for bin in bins {
+        let bar_len = (bin.count * width).div_ceil(most);
+        writeln!(
+            rendered,
+            "{:>label_width$} | {} {}",
+            format!("{}-{}", bin.start, bin.end),
+            "#".repeat(bar_len),
+            bin.count,
+        )
+        .expect("writing to a string");
+    }
+
+    rendered
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{render_histogram, CalorieStats, HistogramBin};
+    use crate::input_parser::{Elfs, ItemCalories};
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    fn example_stats() -> CalorieStats {
+        CalorieStats::from_elfs(&Elfs::from_str(EXAMPLE).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn summary_of_example() {
+        let stats = example_stats();
+
+        assert_eq!(5, stats.count);
+        assert_eq!(11000.0, stats.mean);
+        assert_eq!(10000.0, stats.median);
+        assert_eq!(4000, stats.min);
+        assert_eq!(24000, stats.max);
+        assert_eq!(vec![3, 1, 2, 3, 1], stats.item_counts);
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let stats = example_stats();
+
+        assert_eq!(4000, stats.percentile(0.0).unwrap());
+        assert_eq!(10000, stats.percentile(50.0).unwrap());
+        assert_eq!(11000, stats.percentile(75.0).unwrap());
+        assert_eq!(24000, stats.percentile(100.0).unwrap());
+        assert!(stats.percentile(101.0).is_err());
+    }
+
+    #[test]
+    fn histogram_covers_every_elf() {
+        let bins = example_stats().histogram(2);
+
+        assert_eq!(
+            vec![
+                HistogramBin {
+                    start: 4000,
+                    end: 14000,
+                    count: 4
+                },
+                HistogramBin {
+                    start: 14001,
+                    end: 24000,
+                    count: 1
+                },
+            ],
+            bins
+        );
+        assert_eq!(
+            " 4000-14000 | #### 4\n14001-24000 | # 1\n",
+            render_histogram(&bins, 4)
+        );
+    }
+
+    #[test]
+    fn no_elves_is_an_error() {
+        assert!(CalorieStats::from_elfs(&Elfs(Vec::new())).is_err());
+    }
+
+    #[test]
+    fn histogram_of_full_range_does_not_overflow() {
+        let elfs = Elfs(vec![vec![0], vec![ItemCalories::MAX]]);
+        let bins = CalorieStats::from_elfs(&elfs).unwrap().histogram(2);
+
+        assert_eq!(
+            vec![1, 1],
+            bins.iter().map(|bin| bin.count).collect::<Vec<_>>()
+        );
+        assert_eq!(ItemCalories::MAX, bins[1].end);
+    }
+}
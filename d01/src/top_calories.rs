@@ -0,0 +1,130 @@
+use anyhow::anyhow;
+use aoc_core::groups::Groups;
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+use crate::input_parser::{checked_sum, parse_calories, CalorieError, ItemCalories};
+
+/// Each elf's calorie total, read one group at a time so only the elf
+/// currently being read is ever held in memory
+pub struct ElfTotals<R> {
+    groups: Groups<R>,
+    elf: usize,
+}
+
+impl<R: BufRead> ElfTotals<R> {
+    pub fn new(reader: R) -> Self {
+        ElfTotals {
+            groups: Groups::new(reader),
+            elf: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = anyhow::Result<ItemCalories>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let group = self.groups.next()?;
+        let elf = self.elf;
+        self.elf += 1;
+
+        Some(group.and_then(|group| {
+            let items = group.parse_lines(parse_calories)?;
+            Ok(checked_sum(&items).ok_or(CalorieError::ElfTotalOverflow { elf })?)
+        }))
+    }
+}
+
+/// The `k` largest totals, largest first. Keeps a min-heap of at most `k`
+/// totals so it works on any number of elves in O(n log k).
+pub fn top_n(
+    totals: impl IntoIterator<Item = anyhow::Result<ItemCalories>>,
+    k: usize,
+) -> anyhow::Result<Vec<ItemCalories>> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+
+    for total in totals {
+        heap.push(Reverse(total?));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    if heap.len() < k {
+        Err(anyhow!(
+            "Only {} elves, expected at least {}",
+            heap.len(),
+            k
+        ))?
+    }
+
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(total)| total)
+        .collect())
+}
+
+pub fn top_n_from_reader(reader: impl BufRead, k: usize) -> anyhow::Result<Vec<ItemCalories>> {
+    top_n(ElfTotals::new(reader), k)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{top_n, top_n_from_reader, ElfTotals};
+    use crate::input_parser::{CalorieError, ItemCalories};
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    #[test]
+    fn streams_each_elf_total() {
+        let totals = ElfTotals::new(EXAMPLE.as_bytes())
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(vec![6000, 4000, 11000, 24000, 10000], totals);
+    }
+
+    #[test]
+    fn top_three_of_example() {
+        assert_eq!(
+            vec![24000, 11000, 10000],
+            top_n_from_reader(EXAMPLE.as_bytes(), 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn top_n_matches_sorting_everything() {
+        let totals: Vec<ItemCalories> = (0..500).map(|i| (i * 7919) % 1009).collect();
+        let mut sorted = totals.clone();
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+        for k in [0, 1, 3, 10, 500] {
+            let top = top_n(totals.iter().cloned().map(Ok), k).unwrap();
+            assert_eq!(sorted[..k], top[..]);
+        }
+    }
+
+    #[test]
+    fn too_few_elves_is_an_error() {
+        assert!(top_n([Ok(1), Ok(2)], 3).is_err());
+    }
+
+    #[test]
+    fn bad_line_is_reported() {
+        let err = top_n_from_reader("100\nabc\n\n".as_bytes(), 1).unwrap_err();
+
+        assert!(format!("{}", err).contains("invalid calories"), "{}", err);
+    }
+
+    #[test]
+    fn overflowing_total_names_the_elf() {
+        let input = format!("1\n\n{}\n1\n", ItemCalories::MAX);
+        let err = top_n_from_reader(input.as_bytes(), 1).unwrap_err();
+
+        assert_eq!(
+            Some(&CalorieError::ElfTotalOverflow { elf: 1 }),
+            err.downcast_ref()
+        );
+    }
+}
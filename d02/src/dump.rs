@@ -0,0 +1,188 @@
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+
+use crate::{
+    game::Game,
+    input_parser::{Round, RoundOutcome, RoundStrategies, RoundStrategy, Rounds, Shape},
+    StrategyGuide,
+};
+
+fn shape_named(game: &Game, name: &str) -> anyhow::Result<Shape> {
+    game.shape_by_name(name).ok_or_else(|| {
+        let names: Vec<&str> = game.shapes().map(|shape| game.name(shape)).collect();
+        anyhow!(
+            "{} is not a shape of this game, expected one of {}",
+            name,
+            names.join(", ")
+        )
+    })
+}
+
+/// A round with its shapes written by name, so a dump can be read without
+/// knowing the game's shape order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundRecord {
+    pub opponent: String,
+    pub player: String,
+    pub outcome: RoundOutcome,
+    pub score: i32,
+}
+
+impl RoundRecord {
+    pub fn new(round: &Round, game: &Game) -> Self {
+        RoundRecord {
+            opponent: game.name(round.opponent()).to_string(),
+            player: game.name(round.player()).to_string(),
+            outcome: round.to_player_round_outcome(game),
+            score: round.to_player_score(game),
+        }
+    }
+
+    pub fn to_round(&self, game: &Game) -> anyhow::Result<Round> {
+        Ok(Round::new(
+            shape_named(game, &self.opponent)?,
+            shape_named(game, &self.player)?,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrategyRecord {
+    pub opponent: String,
+    pub player_outcome: RoundOutcome,
+    /// The round the strategy leads to
+    pub round: RoundRecord,
+}
+
+impl StrategyRecord {
+    pub fn new(strategy: &RoundStrategy, game: &Game) -> anyhow::Result<Self> {
+        Ok(StrategyRecord {
+            opponent: game.name(strategy.opponent()).to_string(),
+            player_outcome: strategy.player_outcome(),
+            round: RoundRecord::new(&strategy.to_round(game)?, game),
+        })
+    }
+
+    pub fn to_strategy(&self, game: &Game) -> anyhow::Result<RoundStrategy> {
+        Ok(RoundStrategy::new(
+            shape_named(game, &self.opponent)?,
+            self.player_outcome.clone(),
+        ))
+    }
+}
+
+/// Both readings of a strategy guide with every round's outcome and score.
+/// Loading one back only uses the rounds and strategies, outcomes and
+/// scores are recomputed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuideDump {
+    pub part_one: Vec<RoundRecord>,
+    pub part_two: Vec<StrategyRecord>,
+}
+
+impl GuideDump {
+    pub fn new(guide: &StrategyGuide, game: &Game) -> anyhow::Result<Self> {
+        let part_one = guide
+            .rounds
+            .0
+            .iter()
+            .map(|round| RoundRecord::new(round, game))
+            .collect();
+        let part_two = guide
+            .round_strategies
+            .0
+            .iter()
+            .map(|strategy| StrategyRecord::new(strategy, game))
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(GuideDump { part_one, part_two })
+    }
+
+    /// Fails if any shape name isn't one of `game`'s
+    pub fn into_guide(self, game: &Game) -> anyhow::Result<StrategyGuide> {
+        let rounds = self
+            .part_one
+            .iter()
+            .enumerate()
+            .map(|(i, record)| {
+                record
+                    .to_round(game)
+                    .context(format!("loading part one round {}", i + 1))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        let round_strategies = self
+            .part_two
+            .iter()
+            .enumerate()
+            .map(|(i, record)| {
+                record
+                    .to_strategy(game)
+                    .context(format!("loading part two round {}", i + 1))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(StrategyGuide {
+            rounds: Rounds(rounds),
+            round_strategies: RoundStrategies(round_strategies),
+        })
+    }
+}
+
+pub fn load_json(reader: impl Read, game: &Game) -> anyhow::Result<StrategyGuide> {
+    let dump: GuideDump = serde_json::from_reader(reader).context("parsing guide json")?;
+    dump.into_guide(game)
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{load_json, GuideDump};
+    use crate::{game::Game, input_parser::Columns, StrategyGuide};
+
+    fn example_guide() -> StrategyGuide {
+        let columns = Columns::from_str("A Y\nB X\nC Z").unwrap();
+        StrategyGuide {
+            rounds: columns.rounds().unwrap(),
+            round_strategies: columns.round_strategies().unwrap(),
+        }
+    }
+
+    #[test]
+    fn dump_has_outcomes_and_scores() {
+        let dump = GuideDump::new(&example_guide(), Game::classic()).unwrap();
+        let json = serde_json::to_value(&dump).unwrap();
+
+        assert_eq!(
+            serde_json::json!({"opponent": "Rock", "player": "Paper", "outcome": "Win", "score": 8}),
+            json["part_one"][0]
+        );
+        assert_eq!("Paper", json["part_two"][1]["opponent"]);
+        assert_eq!("Lose", json["part_two"][1]["player_outcome"]);
+        assert_eq!(1, json["part_two"][1]["round"]["score"]);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let dump = GuideDump::new(&example_guide(), Game::classic()).unwrap();
+        let json = serde_json::to_string(&dump).unwrap();
+
+        let loaded = load_json(json.as_bytes(), Game::classic()).unwrap();
+
+        assert_eq!(dump, GuideDump::new(&loaded, Game::classic()).unwrap());
+    }
+
+    #[test]
+    fn shapes_from_another_game_are_an_error() {
+        let json = r#"{"part_one": [{"opponent": "Spock", "player": "Rock", "outcome": "Win", "score": 7}], "part_two": []}"#;
+
+        let err = load_json(json.as_bytes(), Game::classic()).unwrap_err();
+        assert!(
+            format!("{:#}", err).contains("Spock is not a shape"),
+            "{:#}",
+            err
+        );
+        assert!(load_json(json.as_bytes(), Game::rpsls()).is_ok());
+    }
+}
@@ -0,0 +1,325 @@
+use anyhow::{anyhow, Context};
+use serde::Deserialize;
+use std::{collections::HashMap, str::FromStr, sync::OnceLock};
+
+use crate::input_parser::{RoundOutcome, Shape};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ShapeRule {
+    pub name: String,
+    pub score: i32,
+}
+
+/// A game as written in a config file, with `beats` mapping each shape's
+/// name to the names of the shapes it defeats
+#[derive(Debug, Clone, Deserialize)]
+pub struct GameConfig {
+    pub shapes: Vec<ShapeRule>,
+    pub beats: HashMap<String, Vec<String>>,
+}
+
+/// The shapes of a Rock-Paper-Scissors style game, their scores and which
+/// shape beats which. A `Shape` is an index into `shapes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    shapes: Vec<ShapeRule>,
+    /// `beats[winner][loser]`
+    beats: Vec<Vec<bool>>,
+}
+
+impl Game {
+    /// Every pair of different shapes has to be decided by exactly one of
+    /// `beats`, and no shape can beat itself
+    pub fn new(shapes: Vec<ShapeRule>, beats: &[(Shape, Shape)]) -> anyhow::Result<Self> {
+        if shapes.is_empty() {
+            Err(anyhow!("A game needs at least one shape"))?
+        }
+        for (i, shape) in shapes.iter().enumerate() {
+            if shapes[..i].iter().any(|other| other.name == shape.name) {
+                Err(anyhow!("Shape {} is defined twice", shape.name))?
+            }
+        }
+
+        let mut matrix = vec![vec![false; shapes.len()]; shapes.len()];
+        for &(Shape(winner), Shape(loser)) in beats {
+            if winner >= shapes.len() || loser >= shapes.len() {
+                Err(anyhow!(
+                    "Shape {} is not one of the {} shapes",
+                    winner.max(loser),
+                    shapes.len()
+                ))?
+            }
+            if winner == loser {
+                Err(anyhow!("{} can't beat itself", shapes[winner].name))?
+            }
+            matrix[winner][loser] = true;
+        }
+
+        for first in 0..shapes.len() {
+            for second in first + 1..shapes.len() {
+                let (first_name, second_name) = (&shapes[first].name, &shapes[second].name);
+                match (matrix[first][second], matrix[second][first]) {
+                    (true, true) => Err(anyhow!(
+                        "{} and {} can't both beat each other",
+                        first_name,
+                        second_name
+                    ))?,
+                    (false, false) => Err(anyhow!(
+                        "Neither {} nor {} beats the other",
+                        first_name,
+                        second_name
+                    ))?,
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(Game {
+            shapes,
+            beats: matrix,
+        })
+    }
+
+    /// An odd number of shapes in a cycle where each shape beats the ones an
+    /// odd number of steps behind it, scored 1, 2, 3... in order
+    pub fn cyclic(names: &[&str]) -> anyhow::Result<Self> {
+        let n = names.len();
+        if n.is_multiple_of(2) {
+            Err(anyhow!(
+                "A cyclic game needs an odd number of shapes, got {}",
+                n
+            ))?
+        }
+
+        let shapes = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| ShapeRule {
+                name: name.to_string(),
+                score: i as i32 + 1,
+            })
+            .collect();
+        let beats: Vec<(Shape, Shape)> = (0..n)
+            .flat_map(|winner| {
+                (0..n)
+                    .filter(move |&loser| (winner + n - loser) % n % 2 == 1)
+                    .map(move |loser| (Shape(winner), Shape(loser)))
+            })
+            .collect();
+
+        Game::new(shapes, &beats)
+    }
+
+    /// Rock, Paper, Scissors, in the order of `Shape::ROCK`, `Shape::PAPER`
+    /// and `Shape::SCISSORS`
+    pub fn classic() -> &'static Self {
+        static CLASSIC: OnceLock<Game> = OnceLock::new();
+        CLASSIC.get_or_init(|| {
+            Game::cyclic(&["Rock", "Paper", "Scissors"]).expect("classic game is valid")
+        })
+    }
+
+    /// Rock-Paper-Scissors-Lizard-Spock
+    pub fn rpsls() -> &'static Self {
+        static RPSLS: OnceLock<Game> = OnceLock::new();
+        RPSLS.get_or_init(|| {
+            Game::cyclic(&["Rock", "Paper", "Scissors", "Spock", "Lizard"])
+                .expect("rock paper scissors lizard spock is valid")
+        })
+    }
+
+    pub fn from_config(config: GameConfig) -> anyhow::Result<Self> {
+        let find = |name: &str| {
+            config
+                .shapes
+                .iter()
+                .position(|shape| shape.name == name)
+                .map(Shape)
+                .ok_or_else(|| anyhow!("{} is not one of the game's shapes", name))
+        };
+
+        let mut beats = Vec::new();
+        for (winner, losers) in config.beats.iter() {
+            for loser in losers {
+                beats.push((find(winner)?, find(loser)?));
+            }
+        }
+
+        Game::new(config.shapes, &beats)
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.shapes.len()).map(Shape)
+    }
+
+    pub fn shape_by_name(&self, name: &str) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.name == name)
+            .map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0].name
+    }
+
+    pub fn score(&self, shape: Shape) -> i32 {
+        self.shapes[shape.0].score
+    }
+
+    pub fn beats(&self, winner: Shape, loser: Shape) -> bool {
+        self.beats[winner.0][loser.0]
+    }
+
+    /// The outcome of a round for whoever played `player`
+    pub fn outcome(&self, player: Shape, opponent: Shape) -> RoundOutcome {
+        if player == opponent {
+            RoundOutcome::Draw
+        } else if self.beats(player, opponent) {
+            RoundOutcome::Win
+        } else {
+            RoundOutcome::Lose
+        }
+    }
+
+    /// The first shape, in the game's order, that gets `outcome` against `opponent`
+    pub fn shape_for_outcome(
+        &self,
+        opponent: Shape,
+        outcome: RoundOutcome,
+    ) -> anyhow::Result<Shape> {
+        self.shapes()
+            .find(|&player| self.outcome(player, opponent) == outcome)
+            .ok_or_else(|| {
+                anyhow!(
+                    "No shape gets a {:?} against {}",
+                    outcome,
+                    self.name(opponent)
+                )
+            })
+    }
+}
+
+impl FromStr for Game {
+    type Err = anyhow::Error;
+
+    /// A `GameConfig` as JSON
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        let config: GameConfig = serde_json::from_str(input).context("parsing game config")?;
+        Game::from_config(config)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Game;
+    use crate::input_parser::{Round, RoundOutcome, RoundStrategy, Shape};
+
+    #[test]
+    fn classic_matches_the_puzzle_rules() {
+        let game = Game::classic();
+
+        assert!(game.beats(Shape::ROCK, Shape::SCISSORS));
+        assert!(game.beats(Shape::SCISSORS, Shape::PAPER));
+        assert!(game.beats(Shape::PAPER, Shape::ROCK));
+        assert_eq!(
+            vec![1, 2, 3],
+            game.shapes()
+                .map(|shape| game.score(shape))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rpsls_has_the_usual_rules() {
+        let game = Game::rpsls();
+        let shape = |name| game.shape_by_name(name).unwrap();
+
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert!(game.beats(shape(winner), shape(loser)), "{winner} {loser}");
+            assert!(!game.beats(shape(loser), shape(winner)), "{loser} {winner}");
+        }
+    }
+
+    #[test]
+    fn shape_for_outcome_inverts_outcome() {
+        let game = Game::rpsls();
+
+        for opponent in game.shapes() {
+            for outcome in [RoundOutcome::Lose, RoundOutcome::Draw, RoundOutcome::Win] {
+                let player = game.shape_for_outcome(opponent, outcome.clone()).unwrap();
+                assert_eq!(outcome, game.outcome(player, opponent));
+            }
+        }
+    }
+
+    #[test]
+    fn rounds_score_with_any_game() {
+        let game = Game::rpsls();
+        let shape = |name| game.shape_by_name(name).unwrap();
+
+        let round = Round::new(shape("Spock"), shape("Lizard"));
+        assert_eq!(6 + 5, round.to_player_score(game));
+
+        let strategy = RoundStrategy::new(shape("Lizard"), RoundOutcome::Lose);
+        let round = strategy.to_round(game).unwrap();
+        assert_eq!(RoundOutcome::Lose, round.to_player_round_outcome(game));
+        assert_eq!(2, round.to_player_score(game));
+    }
+
+    #[test]
+    fn loads_config() {
+        let game = Game::from_str(
+            r#"{
+                "shapes": [
+                    {"name": "Rock", "score": 1},
+                    {"name": "Paper", "score": 2},
+                    {"name": "Scissors", "score": 3}
+                ],
+                "beats": {
+                    "Rock": ["Scissors"],
+                    "Paper": ["Rock"],
+                    "Scissors": ["Paper"]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(Game::classic(), &game);
+    }
+
+    #[test]
+    fn undecided_pair_is_an_error() {
+        let err = Game::from_str(
+            r#"{
+                "shapes": [{"name": "Rock", "score": 1}, {"name": "Paper", "score": 2}],
+                "beats": {}
+            }"#,
+        )
+        .unwrap_err();
+
+        assert!(
+            err.to_string().contains("Neither Rock nor Paper"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn even_cyclic_game_is_an_error() {
+        assert!(Game::cyclic(&["Rock", "Paper"]).is_err());
+    }
+}
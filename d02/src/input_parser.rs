@@ -1,58 +1,40 @@
 use anyhow::{anyhow, Context};
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use aoc_core::{LineError, ParseError};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, io::BufRead, ops::Range, str::FromStr};
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub enum Shape {
-    Rock,
-    Paper,
-    Scissors,
-}
+use crate::game::Game;
+
+/// A shape of a `Game`, as its index in the game's shapes. It serializes as
+/// that bare index, so dumps meant to be read on their own write shape names
+/// from the game instead, see `dump::RoundRecord`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Shape(pub usize);
 
 impl Shape {
+    pub const ROCK: Shape = Shape(0);
+    pub const PAPER: Shape = Shape(1);
+    pub const SCISSORS: Shape = Shape(2);
+
+    /// A shape of the classic game from its letter in the strategy guide
     pub fn try_from_string(string: String) -> anyhow::Result<Self> {
         match string.as_str() {
-            "A" | "X" => Ok(Shape::Rock),
-            "B" | "Y" => Ok(Shape::Paper),
-            "C" | "Z" => Ok(Shape::Scissors),
+            "A" | "X" => Ok(Shape::ROCK),
+            "B" | "Y" => Ok(Shape::PAPER),
+            "C" | "Z" => Ok(Shape::SCISSORS),
             val => Err(anyhow!("{} is not a valid move", val)),
         }
     }
-
-    pub fn get_outcome_shape(&self, outcome: RoundOutcome) -> Self {
-        match outcome {
-            RoundOutcome::Lose => match self {
-                Self::Rock => Self::Scissors,
-                Self::Paper => Self::Rock,
-                Self::Scissors => Self::Paper,
-            },
-            RoundOutcome::Draw => self.clone(),
-            RoundOutcome::Win => match self {
-                Self::Rock => Self::Paper,
-                Self::Paper => Self::Scissors,
-                Self::Scissors => Self::Rock,
-            },
-        }
-    }
-
-    pub fn to_score(&self) -> i32 {
-        match self {
-            Shape::Rock => 1,
-            Shape::Paper => 2,
-            Shape::Scissors => 3,
-        }
-    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Round {
     opponent: Shape,
     player: Shape,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RoundOutcome {
     Win = 6,
     Draw = 3,
@@ -70,117 +52,306 @@ impl RoundOutcome {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RoundStrategy {
     opponent: Shape,
     player_outcome: RoundOutcome,
 }
 
-impl From<RoundStrategy> for Round {
-    fn from(round_strat: RoundStrategy) -> Round {
-        let RoundStrategy {
+impl RoundStrategy {
+    pub fn new(opponent: Shape, player_outcome: RoundOutcome) -> Self {
+        RoundStrategy {
             opponent,
             player_outcome,
-        } = round_strat;
-        let player = opponent.get_outcome_shape(player_outcome);
-        Round { opponent, player }
+        }
+    }
+
+    pub fn opponent(&self) -> Shape {
+        self.opponent
+    }
+
+    pub fn player_outcome(&self) -> RoundOutcome {
+        self.player_outcome.clone()
+    }
+
+    /// The round where the player picks the shape giving the wanted outcome
+    pub fn to_round(&self, game: &Game) -> anyhow::Result<Round> {
+        let player = game.shape_for_outcome(self.opponent, self.player_outcome.clone())?;
+        Ok(Round::new(self.opponent, player))
     }
 }
 
 impl Round {
-    pub fn to_player_round_outcome(&self) -> RoundOutcome {
-        match self {
-            Round { player, opponent } if opponent == player => RoundOutcome::Draw,
-            Round {
-                player: Shape::Rock,
-                opponent: Shape::Scissors,
-            }
-            | Round {
-                player: Shape::Paper,
-                opponent: Shape::Rock,
-            }
-            | Round {
-                player: Shape::Scissors,
-                opponent: Shape::Paper,
-            } => RoundOutcome::Win,
-            _ => RoundOutcome::Lose,
-        }
+    pub fn new(opponent: Shape, player: Shape) -> Self {
+        Round { opponent, player }
+    }
+
+    pub fn opponent(&self) -> Shape {
+        self.opponent
+    }
+
+    pub fn player(&self) -> Shape {
+        self.player
     }
 
-    pub fn to_player_score(&self) -> i32 {
-        self.to_player_round_outcome() as i32 + self.player.to_score()
+    pub fn to_player_round_outcome(&self, game: &Game) -> RoundOutcome {
+        game.outcome(self.player, self.opponent)
+    }
+
+    pub fn to_player_score(&self, game: &Game) -> i32 {
+        self.to_player_round_outcome(game) as i32 + game.score(self.player)
     }
 }
 
-type Rounds = Vec<Round>;
+/// One line of the guide split into its two columns, before deciding what
+/// the letters mean
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnPair {
+    line_number: usize,
+    line: String,
+    left: Range<usize>,
+    right: Range<usize>,
+}
 
-pub fn parse_input_first(file: &File) -> anyhow::Result<Rounds> {
-    let reader = BufReader::new(file);
-    let mut rounds: Rounds = Vec::new();
-    for line in reader.lines() {
-        let line = line.context("reading line to string")?;
-        let content = line.trim();
-
-        let round = {
-            let chars: Vec<_> = content.chars().collect();
-            let opponent = chars.get(0).map_or_else(
-                || Err(anyhow!("No char 0")),
-                |char| {
-                    Ok(Shape::try_from_string(char.to_string())
-                        .context(format!("deserializing char {}", char))?)
-                },
-            )?;
-            let player = chars.get(2).map_or_else(
-                || Err(anyhow!("No char 2")),
-                |char| {
-                    Ok(Shape::try_from_string(char.to_string())
-                        .context(format!("parsing char {}", char))?)
-                },
-            )?;
-
-            Round { player, opponent }
+impl ColumnPair {
+    fn parse(line_number: usize, line: String) -> Result<Self, ParseError> {
+        let ranges: Vec<Range<usize>> = line
+            .split_whitespace()
+            .map(|token| {
+                let start = token.as_ptr() as usize - line.as_ptr() as usize;
+                start..start + token.len()
+            })
+            .collect();
+
+        let err = match ranges.as_slice() {
+            [left, right] => {
+                return Ok(ColumnPair {
+                    left: left.clone(),
+                    right: right.clone(),
+                    line_number,
+                    line,
+                })
+            }
+            [_, _, extra, ..] => LineError::at_slice(
+                &line,
+                line[extra.start..].trim_end(),
+                "expected 2 columns, got more",
+            ),
+            _ => LineError::at_slice(&line, &line[line.len()..], "expected 2 columns"),
         };
 
-        rounds.push(round);
+        Err(err.at_line(line_number, &line))
+    }
+
+    pub fn left(&self) -> &str {
+        &self.line[self.left.clone()]
+    }
+
+    pub fn right(&self) -> &str {
+        &self.line[self.right.clone()]
+    }
+
+    fn cell_error(&self, cell: &str, err: anyhow::Error) -> ParseError {
+        LineError::at_slice(&self.line, cell, err.to_string()).at_line(self.line_number, &self.line)
+    }
+
+    pub fn interpret<L, R>(
+        &self,
+        left: &impl ColumnInterpreter<L>,
+        right: &impl ColumnInterpreter<R>,
+    ) -> Result<(L, R), ParseError> {
+        let left_value = left
+            .interpret(self.left())
+            .map_err(|err| self.cell_error(self.left(), err))?;
+        let right_value = right
+            .interpret(self.right())
+            .map_err(|err| self.cell_error(self.right(), err))?;
+
+        Ok((left_value, right_value))
+    }
+}
+
+/// Reads what a single guide cell means
+pub trait ColumnInterpreter<T> {
+    fn interpret(&self, cell: &str) -> anyhow::Result<T>;
+}
+
+impl<T, F: Fn(&str) -> anyhow::Result<T>> ColumnInterpreter<T> for F {
+    fn interpret(&self, cell: &str) -> anyhow::Result<T> {
+        self(cell)
+    }
+}
+
+pub fn as_shape(cell: &str) -> anyhow::Result<Shape> {
+    Shape::try_from_string(cell.to_string())
+}
+
+pub fn as_outcome(cell: &str) -> anyhow::Result<RoundOutcome> {
+    RoundOutcome::try_from_string(cell.to_string())
+}
+
+/// Reads cells by looking them up in a fixed table
+#[derive(Debug, Clone)]
+pub struct MappingTable<T>(pub HashMap<String, T>);
+
+impl<T> MappingTable<T> {
+    pub fn new<K: Into<String>>(entries: impl IntoIterator<Item = (K, T)>) -> Self {
+        MappingTable(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
     }
+}
 
-    Ok(rounds)
+impl<T: Clone> ColumnInterpreter<T> for MappingTable<T> {
+    fn interpret(&self, cell: &str) -> anyhow::Result<T> {
+        self.0.get(cell).cloned().ok_or_else(|| {
+            let mut keys: Vec<&str> = self.0.keys().map(String::as_str).collect();
+            keys.sort_unstable();
+            anyhow!("{} is not one of {}", cell, keys.join(", "))
+        })
+    }
 }
 
-type RoundStrategies = Vec<RoundStrategy>;
+/// The whole guide read once, to be interpreted any number of ways
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns(pub Vec<ColumnPair>);
+
+impl Columns {
+    pub fn interpret<L, R>(
+        &self,
+        left: &impl ColumnInterpreter<L>,
+        right: &impl ColumnInterpreter<R>,
+    ) -> Result<Vec<(L, R)>, ParseError> {
+        self.0
+            .iter()
+            .map(|pair| pair.interpret(left, right))
+            .collect()
+    }
+
+    /// Both columns as shapes, as in part one
+    pub fn rounds(&self) -> anyhow::Result<Rounds> {
+        Ok(Rounds(
+            self.interpret(&as_shape, &as_shape)?
+                .into_iter()
+                .map(|(opponent, player)| Round::new(opponent, player))
+                .collect(),
+        ))
+    }
 
-pub fn parse_input_second(file: &File) -> anyhow::Result<RoundStrategies> {
-    let reader = BufReader::new(file);
-    let mut rounds: RoundStrategies = Vec::new();
-    for line in reader.lines() {
+    /// The second column as the outcome to aim for, as in part two
+    pub fn round_strategies(&self) -> anyhow::Result<RoundStrategies> {
+        Ok(RoundStrategies(
+            self.interpret(&as_shape, &as_outcome)?
+                .into_iter()
+                .map(|(opponent, outcome)| RoundStrategy::new(opponent, outcome))
+                .collect(),
+        ))
+    }
+}
+
+impl FromStr for Columns {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        parse_columns(input.as_bytes())
+    }
+}
+
+pub fn parse_columns(reader: impl BufRead) -> anyhow::Result<Columns> {
+    let mut pairs = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
         let line = line.context("reading line to string")?;
-        let content = line.trim();
-
-        let round = {
-            let chars: Vec<_> = content.chars().collect();
-            let opponent = chars.get(0).map_or_else(
-                || Err(anyhow!("No char 0")),
-                |char| {
-                    Ok(Shape::try_from_string(char.to_string())
-                        .context(format!("deserializing char {}", char))?)
-                },
-            )?;
-            let player_outcome = chars.get(2).map_or_else(
-                || Err(anyhow!("No char 2")),
-                |char| {
-                    Ok(RoundOutcome::try_from_string(char.to_string())
-                        .context(format!("parsing char {}", char))?)
-                },
-            )?;
-
-            RoundStrategy {
-                player_outcome,
-                opponent,
-            }
-        };
+        pairs.push(ColumnPair::parse(i + 1, line)?);
+    }
+
+    Ok(Columns(pairs))
+}
 
-        rounds.push(round);
+#[derive(Debug)]
+pub struct Rounds(pub Vec<Round>);
+
+impl FromStr for Rounds {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        Columns::from_str(input)?.rounds()
     }
+}
+
+#[derive(Debug)]
+pub struct RoundStrategies(pub Vec<RoundStrategy>);
+
+impl FromStr for RoundStrategies {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        Columns::from_str(input)?.round_strategies()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use aoc_core::ParseError;
+
+    use super::{as_shape, Columns, MappingTable, RoundOutcome, Shape};
 
-    Ok(rounds)
+    const EXAMPLE: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn reads_columns_once_for_both_parts() {
+        let columns = Columns::from_str(EXAMPLE).unwrap();
+
+        assert_eq!(
+            vec![("A", "Y"), ("B", "X"), ("C", "Z")],
+            columns
+                .0
+                .iter()
+                .map(|pair| (pair.left(), pair.right()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(3, columns.rounds().unwrap().0.len());
+        assert_eq!(3, columns.round_strategies().unwrap().0.len());
+    }
+
+    #[test]
+    fn custom_mapping_table() {
+        let columns = Columns::from_str(EXAMPLE).unwrap();
+        let outcomes = MappingTable::new([
+            ("X", RoundOutcome::Win),
+            ("Y", RoundOutcome::Draw),
+            ("Z", RoundOutcome::Lose),
+        ]);
+
+        assert_eq!(
+            vec![
+                (Shape::ROCK, RoundOutcome::Draw),
+                (Shape::PAPER, RoundOutcome::Win),
+                (Shape::SCISSORS, RoundOutcome::Lose),
+            ],
+            columns.interpret(&as_shape, &outcomes).unwrap()
+        );
+    }
+
+    #[test]
+    fn bad_cell_reports_its_column() {
+        let columns = Columns::from_str("A Y\nB   W").unwrap();
+        let err = columns.rounds().unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!((2, 5), (err.line_number, err.column));
+        assert!(err.message.contains("W is not a valid move"), "{}", err);
+    }
+
+    #[test]
+    fn wrong_number_of_columns_is_an_error() {
+        for input in ["A", "A Y Z", "A Y\n\nB X"] {
+            let err = Columns::from_str(input).unwrap_err();
+            assert!(err.downcast_ref::<ParseError>().is_some(), "{}", err);
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use anyhow::Context;
+use aoc_core::Solution;
+use std::io::BufRead;
+
+pub mod dump;
+pub mod game;
+pub mod input_parser;
+pub mod optimiser;
+pub mod simulation;
+
+use game::Game;
+use input_parser::{RoundStrategies, Rounds};
+
+pub struct Day02;
+
+#[derive(Debug)]
+pub struct StrategyGuide {
+    pub rounds: Rounds,
+    pub round_strategies: RoundStrategies,
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = StrategyGuide;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    /// Either the puzzle's letter columns or a JSON dump from `dump::GuideDump`
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<StrategyGuide> {
+        let first_char = reader
+            .fill_buf()
+            .context("reading input")?
+            .iter()
+            .find(|byte| !byte.is_ascii_whitespace())
+            .copied();
+        if first_char == Some(b'{') {
+            return dump::load_json(reader, Game::classic());
+        }
+
+        //Both parts interpret the same columns differently
+        let columns = input_parser::parse_columns(reader)?;
+
+        Ok(StrategyGuide {
+            rounds: columns.rounds()?,
+            round_strategies: columns.round_strategies()?,
+        })
+    }
+
+    fn part_one(guide: &StrategyGuide) -> anyhow::Result<i32> {
+        Ok(guide
+            .rounds
+            .0
+            .iter()
+            .map(|round| round.to_player_score(Game::classic()))
+            .sum())
+    }
+
+    fn part_two(guide: &StrategyGuide) -> anyhow::Result<i32> {
+        guide
+            .round_strategies
+            .0
+            .iter()
+            .map(|round_strat| {
+                Ok(round_strat
+                    .to_round(Game::classic())?
+                    .to_player_score(Game::classic()))
+            })
+            .sum()
+    }
+}
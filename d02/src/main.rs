@@ -1,30 +1,44 @@
-use anyhow::Context;
-use std::fs::File;
+use anyhow::anyhow;
+use d2::{dump::GuideDump, game::Game, optimiser, Day02};
+use std::{env, path::Path};
 
-use crate::input_parser::Round;
+fn print_analysis(path: &Path) -> anyhow::Result<()> {
+    let guide = aoc_core::parse_file::<Day02>(path)?;
+    let analysis = optimiser::analyse(&guide.rounds);
 
-mod input_parser;
-fn main() -> anyhow::Result<()> {
-    let input_file = File::open("./my_input.txt").context("opening file")?;
-
-    let parsed_one = input_parser::parse_input_first(&input_file).context("parsing file")?;
-
-    let total_score: i32 = parsed_one.iter().map(|round| round.to_player_score()).sum();
-
-    println!("player fist total: {:?}", total_score);
-
-    let input_file = File::open("./my_input.txt").context("opening file")?;
-
-    let parsed_two = input_parser::parse_input_second(&input_file).context("parsing file")?;
+    println!("Max score: {}", analysis.max_score);
+    println!("Min score: {}", analysis.min_score);
+    for mapping_score in analysis.mapping_scores.iter() {
+        println!("{}: {}", mapping_score.mapping, mapping_score.score);
+    }
+    println!("Likely meant: {}", analysis.likely_mapping().mapping);
 
-    let rounds_two: Vec<_> = parsed_two
-        .iter()
-        .map(|round_strat| Round::from(round_strat.clone()))
-        .collect();
+    Ok(())
+}
 
-    let total_score_two: i32 = rounds_two.iter().map(|round| round.to_player_score()).sum();
+fn print_dump(path: &Path, format: Option<&str>) -> anyhow::Result<()> {
+    match format {
+        Some("json") => (),
+        Some(format) => Err(anyhow!("{} is not a dump format, expected json", format))?,
+        None => Err(anyhow!("--dump needs a format, e.g. --dump json"))?,
+    }
 
-    println!("player second total: {:?}", total_score_two);
+    let guide = aoc_core::parse_file::<Day02>(path)?;
+    let dump = GuideDump::new(&guide, Game::classic())?;
+    println!("{}", serde_json::to_string_pretty(&dump)?);
 
     Ok(())
 }
+
+fn main() -> anyhow::Result<()> {
+    let input_path = Path::new("./my_input.txt");
+    let args: Vec<String> = env::args().collect();
+
+    if let Some(i) = args.iter().position(|arg| arg == "--dump") {
+        print_dump(input_path, args.get(i + 1).map(String::as_str))
+    } else if args.iter().any(|arg| arg == "--optimise") {
+        print_analysis(input_path)
+    } else {
+        aoc_core::print_answers::<Day02>(input_path)
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use crate::{
+    game::Game,
+    input_parser::{Round, Rounds, Shape},
+};
+
+/// Which classic shape each of the guide's X, Y and Z stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping(pub [Shape; 3]);
+
+impl Mapping {
+    /// The reading from part one, X for Rock, Y for Paper and Z for Scissors
+    pub const IDENTITY: Mapping = Mapping([Shape::ROCK, Shape::PAPER, Shape::SCISSORS]);
+
+    /// All 6 ways to assign the three shapes to X, Y and Z
+    pub fn all() -> Vec<Mapping> {
+        let shapes = [Shape::ROCK, Shape::PAPER, Shape::SCISSORS];
+        let mut mappings = Vec::with_capacity(6);
+        for x in shapes {
+            for y in shapes.into_iter().filter(|&y| y != x) {
+                for z in shapes.into_iter().filter(|&z| z != x && z != y) {
+                    mappings.push(Mapping([x, y, z]));
+                }
+            }
+        }
+        mappings
+    }
+
+    /// `letter` is the shape part one read the letter as
+    pub fn apply(&self, letter: Shape) -> Shape {
+        self.0[letter.0]
+    }
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let game = Game::classic();
+        let [x, y, z] = self.0;
+        write!(
+            f,
+            "X={}, Y={}, Z={}",
+            game.name(x),
+            game.name(y),
+            game.name(z)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingScore {
+    pub mapping: Mapping,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuideAnalysis {
+    /// Best possible score against the opponent's moves, whatever the guide says
+    pub max_score: i32,
+    pub min_score: i32,
+    /// One entry per mapping, in the order of `Mapping::all`
+    pub mapping_scores: Vec<MappingScore>,
+}
+
+impl GuideAnalysis {
+    /// The highest scoring mapping, taken as what the guide really meant.
+    /// Ties go to the earlier mapping in `Mapping::all`.
+    pub fn likely_mapping(&self) -> &MappingScore {
+        self.mapping_scores
+            .iter()
+            .rev()
+            .max_by_key(|mapping_score| mapping_score.score)
+            .expect("there are always 6 mappings")
+    }
+}
+
+pub fn score_with_mapping(rounds: &Rounds, mapping: Mapping) -> i32 {
+    let game = Game::classic();
+    rounds
+        .0
+        .iter()
+        .map(|round| {
+            Round::new(round.opponent(), mapping.apply(round.player())).to_player_score(game)
+        })
+        .sum()
+}
+
+/// `rounds` as parsed for part one, so each player shape is the letter read
+/// with `Mapping::IDENTITY`
+pub fn analyse(rounds: &Rounds) -> GuideAnalysis {
+    let game = Game::classic();
+
+    let (mut min_score, mut max_score) = (0, 0);
+    for round in rounds.0.iter() {
+        let (min, max) = game
+            .shapes()
+            .map(|player| Round::new(round.opponent(), player).to_player_score(game))
+            .fold((i32::MAX, i32::MIN), |(min, max), score| {
+                (min.min(score), max.max(score))
+            });
+        min_score += min;
+        max_score += max;
+    }
+
+    GuideAnalysis {
+        max_score,
+        min_score,
+        mapping_scores: Mapping::all()
+            .into_iter()
+            .map(|mapping| MappingScore {
+                mapping,
+                score: score_with_mapping(rounds, mapping),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{analyse, score_with_mapping, Mapping};
+    use crate::input_parser::{Rounds, Shape};
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn six_distinct_mappings() {
+        let mappings = Mapping::all();
+
+        assert_eq!(6, mappings.len());
+        assert_eq!(Mapping::IDENTITY, mappings[0]);
+        for (i, mapping) in mappings.iter().enumerate() {
+            assert!(!mappings[..i].contains(mapping));
+        }
+    }
+
+    #[test]
+    fn identity_mapping_is_part_one() {
+        let rounds = Rounds::from_str(EXAMPLE).unwrap();
+
+        assert_eq!(15, score_with_mapping(&rounds, Mapping::IDENTITY));
+    }
+
+    #[test]
+    fn analyse_example() {
+        let analysis = analyse(&Rounds::from_str(EXAMPLE).unwrap());
+
+        assert_eq!(24, analysis.max_score);
+        assert_eq!(6, analysis.min_score);
+        for mapping_score in analysis.mapping_scores.iter() {
+            assert!((analysis.min_score..=analysis.max_score).contains(&mapping_score.score));
+        }
+
+        let likely = analysis.likely_mapping();
+        assert_eq!(24, likely.score);
+        assert_eq!(
+            Mapping([Shape::SCISSORS, Shape::PAPER, Shape::ROCK]),
+            likely.mapping
+        );
+        assert_eq!("X=Scissors, Y=Paper, Z=Rock", likely.mapping.to_string());
+    }
+}
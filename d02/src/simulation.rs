@@ -0,0 +1,271 @@
+use anyhow::{anyhow, Context};
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng, RngCore, SeedableRng,
+};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    game::Game,
+    input_parser::{Round, RoundOutcome, RoundStrategies, Rounds, Shape},
+};
+
+/// How an opponent picks its shape each round
+pub trait Opponent {
+    fn play(&mut self, game: &Game, rng: &mut dyn RngCore) -> Shape;
+
+    /// Called after every round with the shape the player used
+    fn observe(&mut self, _player: Shape) {}
+}
+
+fn random_shape(game: &Game, rng: &mut dyn RngCore) -> Shape {
+    Shape(rng.gen_range(0..game.shapes().count()))
+}
+
+/// Every shape equally likely
+pub struct UniformRandom;
+
+impl Opponent for UniformRandom {
+    fn play(&mut self, game: &Game, rng: &mut dyn RngCore) -> Shape {
+        random_shape(game, rng)
+    }
+}
+
+/// Each shape picked in proportion to its weight
+pub struct Biased {
+    weights: WeightedIndex<f64>,
+}
+
+impl Biased {
+    /// One weight per shape of `game`, in the game's order
+    pub fn new(game: &Game, weights: &[f64]) -> anyhow::Result<Self> {
+        let no_shapes = game.shapes().count();
+        if weights.len() != no_shapes {
+            Err(anyhow!(
+                "Got {} weights for a game of {} shapes",
+                weights.len(),
+                no_shapes
+            ))?
+        }
+
+        Ok(Biased {
+            weights: WeightedIndex::new(weights).context("invalid shape weights")?,
+        })
+    }
+}
+
+impl Opponent for Biased {
+    fn play(&mut self, _game: &Game, rng: &mut dyn RngCore) -> Shape {
+        Shape(self.weights.sample(rng))
+    }
+}
+
+/// Plays whatever beats the player's most used shape so far, randomly
+/// until it has seen a round
+#[derive(Default)]
+pub struct FrequencyCounting {
+    counts: Vec<usize>,
+}
+
+impl Opponent for FrequencyCounting {
+    fn play(&mut self, game: &Game, rng: &mut dyn RngCore) -> Shape {
+        let most_used = self
+            .counts
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count > 0)
+            .max_by_key(|&(shape, &count)| (count, std::cmp::Reverse(shape)))
+            .map(|(shape, _)| Shape(shape));
+
+        //The player's favourite can be a shape nothing beats in some games
+        most_used
+            .and_then(|shape| game.shape_for_outcome(shape, RoundOutcome::Win).ok())
+            .unwrap_or_else(|| random_shape(game, rng))
+    }
+
+    fn observe(&mut self, player: Shape) {
+        if self.counts.len() <= player.0 {
+            self.counts.resize(player.0 + 1, 0);
+        }
+        self.counts[player.0] += 1;
+    }
+}
+
+/// Copies the player's shape from the previous round, random for the first
+#[derive(Default)]
+pub struct RepeatLast {
+    last: Option<Shape>,
+}
+
+impl Opponent for RepeatLast {
+    fn play(&mut self, game: &Game, rng: &mut dyn RngCore) -> Shape {
+        self.last.unwrap_or_else(|| random_shape(game, rng))
+    }
+
+    fn observe(&mut self, player: Shape) {
+        self.last = Some(player);
+    }
+}
+
+/// The shapes the player uses when reading the guide as in part one
+pub fn player_moves_from_rounds(rounds: &Rounds) -> Vec<Shape> {
+    rounds.0.iter().map(|round| round.player()).collect()
+}
+
+/// The shapes the player uses when reading the guide as in part two,
+/// trusting that the opponent plays what the guide expects
+pub fn player_moves_from_strategies(
+    strategies: &RoundStrategies,
+    game: &Game,
+) -> anyhow::Result<Vec<Shape>> {
+    strategies
+        .0
+        .iter()
+        .map(|strategy| Ok(strategy.to_round(game)?.player()))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationReport {
+    pub rounds: usize,
+    pub total_score: i64,
+    /// Mean score of a single round
+    pub expected_score: f64,
+    pub score_variance: f64,
+    pub win_rate: f64,
+    pub draw_rate: f64,
+    pub loss_rate: f64,
+}
+
+/// Plays `rounds` rounds against `opponent`, cycling through `player_moves`.
+/// The same seed always plays out the same way.
+pub fn simulate(
+    game: &Game,
+    player_moves: &[Shape],
+    opponent: &mut dyn Opponent,
+    rounds: usize,
+    seed: u64,
+) -> anyhow::Result<SimulationReport> {
+    if player_moves.is_empty() || rounds == 0 {
+        Err(anyhow!("Need at least one player move and one round"))?
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let (mut total_score, mut sum_of_squares) = (0_i64, 0_f64);
+    let (mut wins, mut draws, mut losses) = (0, 0, 0);
+
+    for &player in player_moves.iter().cycle().take(rounds) {
+        let round = Round::new(opponent.play(game, &mut rng), player);
+        opponent.observe(player);
+
+        match round.to_player_round_outcome(game) {
+            RoundOutcome::Win => wins += 1,
+            RoundOutcome::Draw => draws += 1,
+            RoundOutcome::Lose => losses += 1,
+        }
+        let score = round.to_player_score(game);
+        total_score += score as i64;
+        sum_of_squares += (score as f64).powi(2);
+    }
+
+    let n = rounds as f64;
+    let expected_score = total_score as f64 / n;
+    Ok(SimulationReport {
+        rounds,
+        total_score,
+        expected_score,
+        score_variance: sum_of_squares / n - expected_score.powi(2),
+        win_rate: wins as f64 / n,
+        draw_rate: draws as f64 / n,
+        loss_rate: losses as f64 / n,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{
+        player_moves_from_strategies, simulate, Biased, FrequencyCounting, RepeatLast,
+        UniformRandom,
+    };
+    use crate::{
+        game::Game,
+        input_parser::{RoundStrategies, Shape},
+    };
+
+    #[test]
+    fn same_seed_same_report() {
+        let game = Game::classic();
+        let moves = [Shape::ROCK, Shape::PAPER];
+
+        let first = simulate(game, &moves, &mut UniformRandom, 1000, 7).unwrap();
+        let second = simulate(game, &moves, &mut UniformRandom, 1000, 7).unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn uniform_opponent_is_a_fair_game() {
+        let report = simulate(
+            Game::classic(),
+            &[Shape::ROCK],
+            &mut UniformRandom,
+            30_000,
+            0,
+        )
+        .unwrap();
+
+        for rate in [report.win_rate, report.draw_rate, report.loss_rate] {
+            assert!((rate - 1.0 / 3.0).abs() < 0.02, "{:?}", report);
+        }
+        //Always rock: 1 plus 0, 3 or 6 equally often
+        assert!((report.expected_score - 4.0).abs() < 0.1, "{:?}", report);
+        assert!((report.score_variance - 6.0).abs() < 0.3, "{:?}", report);
+    }
+
+    #[test]
+    fn biased_opponent_only_plays_weighted_shapes() {
+        let game = Game::classic();
+        let mut opponent = Biased::new(game, &[1.0, 0.0, 0.0]).unwrap();
+
+        let report = simulate(game, &[Shape::PAPER], &mut opponent, 100, 0).unwrap();
+
+        assert_eq!(1.0, report.win_rate);
+        assert_eq!(800, report.total_score);
+        assert_eq!(0.0, report.score_variance);
+        assert!(Biased::new(game, &[1.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn adaptive_opponents_punish_a_predictable_player() {
+        let game = Game::classic();
+
+        let report = simulate(
+            game,
+            &[Shape::ROCK],
+            &mut FrequencyCounting::default(),
+            100,
+            0,
+        )
+        .unwrap();
+        assert!(report.loss_rate >= 0.99, "{:?}", report);
+
+        let report = simulate(game, &[Shape::ROCK], &mut RepeatLast::default(), 100, 0).unwrap();
+        assert!(report.draw_rate >= 0.99, "{:?}", report);
+    }
+
+    #[test]
+    fn guide_moves_cycle() {
+        let game = Game::classic();
+        let strategies = RoundStrategies::from_str("A Y\nB X\nC Z").unwrap();
+        let moves = player_moves_from_strategies(&strategies, game).unwrap();
+        assert_eq!(vec![Shape::ROCK, Shape::ROCK, Shape::ROCK], moves);
+
+        let mut opponent = Biased::new(game, &[0.0, 0.0, 1.0]).unwrap();
+        let report = simulate(game, &moves, &mut opponent, 7, 0).unwrap();
+
+        assert_eq!(7, report.rounds);
+        assert_eq!(7 * 7, report.total_score);
+    }
+}
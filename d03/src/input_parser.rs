@@ -1,9 +1,8 @@
 use anyhow::{anyhow, Context};
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use aoc_core::LineError;
+use std::io::BufRead;
+
+use crate::item_set::ItemSet;
 
 type Item = char;
 type Priority = i32;
@@ -11,60 +10,81 @@ type Items = Vec<Item>;
 
 #[derive(Debug, Clone)]
 pub struct Rucksack {
-    pub items: Items,
+    items: Items,
+    compartments: [ItemSet; 2],
 }
 
 pub struct PriorityMap {
-    map: HashMap<Item, Priority>,
+    /// Items in priority order, a-z then A-Z, for going back from an `ItemSet`
+    by_priority: Vec<Item>,
+}
+
+impl Default for PriorityMap {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl PriorityMap {
     pub fn new() -> Self {
-        let lower_alphabet_with_vals: Vec<(Item, Priority)> = ('a'..='z').zip(1..=26).collect();
-        let upper_alphabet_with_vals: Vec<(Item, Priority)> = ('A'..='Z').zip(27..=52).collect();
+        let by_priority = ('a'..='z').chain('A'..='Z').collect();
 
-        let mut map = HashMap::new();
+        PriorityMap { by_priority }
+    }
 
-        for i in 0..26 {
-            map.insert(lower_alphabet_with_vals[i].0, lower_alphabet_with_vals[i].1);
-            map.insert(upper_alphabet_with_vals[i].0, upper_alphabet_with_vals[i].1);
+    pub fn try_get_item_priority(&self, item: &Item) -> anyhow::Result<Priority> {
+        match item {
+            'a'..='z' => Ok(*item as Priority - 'a' as Priority + 1),
+            'A'..='Z' => Ok(*item as Priority - 'A' as Priority + 27),
+            _ => Err(anyhow!("Item '{}' does not have a value", item)),
         }
+    }
 
-        PriorityMap { map }
+    pub fn try_get_item_set(&self, items: &[Item]) -> anyhow::Result<ItemSet> {
+        items.iter().try_fold(ItemSet::EMPTY, |set, item| {
+            let priority = self.try_get_item_priority(item)?;
+            Ok(set | ItemSet::with_priority(priority as u32))
+        })
     }
 
-    pub fn try_get_item_priority(&self, item: &Item) -> anyhow::Result<&Priority> {
-        self.map
-            .get(item)
-            .ok_or_else(|| anyhow!("Item '{}' does not have a value", item))
+    /// The items of `set`, lowest priority first
+    pub fn get_items<'a>(&'a self, set: ItemSet) -> impl Iterator<Item = Item> + 'a {
+        set.priorities()
+            .map(|priority| self.by_priority[priority as usize - 1])
     }
 }
 
 impl Rucksack {
-    pub fn get_first_comparment(&self) -> Items {
-        self.items[0..self.items.len() / 2]
-            .iter()
-            .cloned()
-            .collect()
+    pub fn new(items: Items, priority_map: &PriorityMap) -> anyhow::Result<Self> {
+        let (first, second) = items.split_at(items.len() / 2);
+        let compartments = [
+            priority_map.try_get_item_set(first)?,
+            priority_map.try_get_item_set(second)?,
+        ];
+
+        Ok(Rucksack {
+            items,
+            compartments,
+        })
     }
-    pub fn get_second_comparment(&self) -> Items {
-        self.items[(self.items.len() / 2)..self.items.len()]
-            .iter()
-            .cloned()
-            .collect()
+
+    pub fn items(&self) -> &[Item] {
+        &self.items
     }
 
-    pub fn get_common_item(&self) -> Item {
-        *self
-            .get_first_comparment()
-            .iter()
-            .find(|&item| {
-                self.get_second_comparment()
-                    .iter()
-                    .find(|&item2| item == item2)
-                    .is_some()
-            })
-            .expect("should be a common item in each compartment")
+    pub fn get_item_set(&self) -> ItemSet {
+        self.compartments[0] | self.compartments[1]
+    }
+
+    pub fn get_common_items(&self) -> ItemSet {
+        self.compartments[0] & self.compartments[1]
+    }
+
+    pub fn get_common_item(&self, priority_map: &PriorityMap) -> anyhow::Result<Item> {
+        priority_map
+            .get_items(self.get_common_items())
+            .next()
+            .ok_or_else(|| anyhow!("No item is in both compartments"))
     }
 }
 pub type RucksackItems = Vec<Rucksack>;
@@ -75,56 +95,156 @@ pub struct ElfGroup {
 }
 
 impl ElfGroup {
-    pub fn get_group_badge(&self) -> Item {
+    fn shared_item_set(&self) -> ItemSet {
         self.group
             .iter()
-            .enumerate()
-            .find_map(|(i, elf)| {
-                elf.items
-                    .iter()
-                    .find(|&item| {
-                        self.group.iter().enumerate().all(|(i2, elf2)| {
-                            if i2 == i {
-                                true
-                            } else {
-                                elf2.items.iter().find(|&item2| item2 == item).is_some()
-                            }
-                        })
-                    })
-                    .cloned()
-            })
-            .expect("Unable to find group badeg")
+            .map(|elf| elf.get_item_set())
+            .reduce(|shared, items| shared & items)
+            .unwrap_or(ItemSet::EMPTY)
+    }
+
+    /// Every item carried by all of the group, lowest priority first. Not
+    /// checked, use `get_group_badge` for the validated badge.
+    pub fn get_shared_items(&self, priority_map: &PriorityMap) -> Items {
+        priority_map.get_items(self.shared_item_set()).collect()
+    }
+
+    /// The one item the whole group carries, an error if there isn't exactly one
+    pub fn get_group_badge(&self, priority_map: &PriorityMap) -> anyhow::Result<Item> {
+        match self.get_shared_items(priority_map).as_slice() {
+            [badge] => Ok(*badge),
+            [] => Err(anyhow!("No item is carried by every elf in the group")),
+            shared => Err(anyhow!(
+                "Badge is not unique, every elf in the group carries {}",
+                shared.iter().collect::<String>()
+            )),
+        }
     }
 }
 
 pub type ElfGroups = Vec<ElfGroup>;
 
-pub fn subdivide_into_elf_groups(elfs: RucksackItems) -> ElfGroups {
-    let mut elf_groups = Vec::new();
-    let mut group = Vec::new();
-
-    for elf in elfs {
-        group.push(elf);
-        if group.len() >= 3 {
-            elf_groups.push(ElfGroup {
-                group: group.clone(),
-            });
-            group = Vec::new();
-        }
+/// Splits the elves into consecutive groups of `group_size`, which has to
+/// divide the number of elves exactly
+pub fn subdivide_into_elf_groups(
+    elfs: RucksackItems,
+    group_size: usize,
+) -> anyhow::Result<ElfGroups> {
+    if group_size == 0 {
+        Err(anyhow!("Group size has to be at least 1"))?
+    }
+    let leftover = elfs.len() % group_size;
+    if leftover != 0 {
+        Err(anyhow!(
+            "{} elves left over after splitting {} elves into groups of {}",
+            leftover,
+            elfs.len(),
+            group_size
+        ))?
     }
-    elf_groups
+
+    Ok(elfs
+        .chunks_exact(group_size)
+        .map(|group| ElfGroup {
+            group: group.to_vec(),
+        })
+        .collect())
 }
 
-pub fn parse_input(file: &File) -> anyhow::Result<RucksackItems> {
-    let reader = BufReader::new(file);
+pub fn parse_input(reader: impl BufRead) -> anyhow::Result<RucksackItems> {
+    let priority_map = PriorityMap::new();
     let mut rucksacks: RucksackItems = Vec::new();
-    for line in reader.lines() {
+    for (i, line) in reader.lines().enumerate() {
         let line = line.context("reading line to string")?;
         let content = line.trim();
 
+        if let Some((start, item)) = content
+            .char_indices()
+            .find(|(_, item)| !item.is_ascii_alphabetic())
+        {
+            let item_str = &content[start..start + item.len_utf8()];
+            Err(
+                LineError::at_slice(&line, item_str, format!("'{}' is not a valid item", item))
+                    .at_line(i + 1, &line),
+            )?
+        }
+
         let items = content.chars().collect();
-        rucksacks.push(Rucksack { items });
+        rucksacks.push(Rucksack::new(items, &priority_map)?);
     }
 
     Ok(rucksacks)
 }
+
+#[cfg(test)]
+mod test {
+    use super::{parse_input, subdivide_into_elf_groups, PriorityMap};
+    use crate::GROUP_SIZE;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn common_items_of_example() {
+        let priority_map = PriorityMap::new();
+        let rucksacks = parse_input(EXAMPLE.as_bytes()).unwrap();
+
+        let common_items = rucksacks
+            .iter()
+            .map(|rucksack| rucksack.get_common_item(&priority_map).unwrap())
+            .collect::<String>();
+        assert_eq!("pLPvts", common_items);
+
+        let badges = subdivide_into_elf_groups(rucksacks, GROUP_SIZE)
+            .unwrap()
+            .iter()
+            .map(|group| group.get_group_badge(&priority_map).unwrap())
+            .collect::<String>();
+        assert_eq!("rZ", badges);
+    }
+
+    #[test]
+    fn priorities_follow_the_item_order() {
+        let priority_map = PriorityMap::new();
+
+        for (i, item) in priority_map.by_priority.iter().enumerate() {
+            assert_eq!(
+                i as i32 + 1,
+                priority_map.try_get_item_priority(item).unwrap()
+            );
+        }
+        assert!(priority_map.try_get_item_priority(&'1').is_err());
+    }
+
+    #[test]
+    fn leftover_elves_are_an_error() {
+        let rucksacks = parse_input(EXAMPLE.as_bytes()).unwrap();
+
+        let err = subdivide_into_elf_groups(rucksacks.clone(), 4).unwrap_err();
+        assert!(err.to_string().contains("2 elves left over"), "{}", err);
+        assert!(subdivide_into_elf_groups(rucksacks.clone(), 0).is_err());
+        assert_eq!(6, subdivide_into_elf_groups(rucksacks, 1).unwrap().len());
+    }
+
+    #[test]
+    fn badge_has_to_be_unique() {
+        let priority_map = PriorityMap::new();
+        let rucksacks = parse_input("abcXYd\nXdbYpq\nqbYwXe\nfghi".as_bytes()).unwrap();
+        let groups = subdivide_into_elf_groups(rucksacks, 2).unwrap();
+
+        assert_eq!(
+            vec!['b', 'd', 'X', 'Y'],
+            groups[0].get_shared_items(&priority_map)
+        );
+        let err = groups[0].get_group_badge(&priority_map).unwrap_err();
+        assert!(err.to_string().contains("bdXY"), "{}", err);
+
+        let err = groups[1].get_group_badge(&priority_map).unwrap_err();
+        assert!(err.to_string().contains("No item"), "{}", err);
+        assert!(groups[1].get_shared_items(&priority_map).is_empty());
+    }
+}
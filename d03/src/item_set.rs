@@ -0,0 +1,75 @@
+use std::ops::{BitAnd, BitOr};
+
+/// A set of items as a bitmask, the item with priority `p` at bit `p - 1`
+/// following the `PriorityMap` ordering of a-z then A-Z
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemSet(pub u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn with_priority(priority: u32) -> Self {
+        debug_assert!((1..=52).contains(&priority));
+        ItemSet(1 << (priority - 1))
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Priorities of the items in the set, lowest first
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let slot = bits.trailing_zeros();
+            bits &= bits - 1;
+            Some(slot + 1)
+        })
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ItemSet;
+
+    #[test]
+    fn priorities_come_out_lowest_first() {
+        let set =
+            ItemSet::with_priority(52) | ItemSet::with_priority(1) | ItemSet::with_priority(16);
+
+        assert_eq!(3, set.len());
+        assert_eq!(vec![1, 16, 52], set.priorities().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn intersection_keeps_shared_items() {
+        let first = ItemSet::with_priority(3) | ItemSet::with_priority(4);
+        let second = ItemSet::with_priority(4) | ItemSet::with_priority(5);
+
+        assert_eq!(ItemSet::with_priority(4), first & second);
+        assert!((first & ItemSet::with_priority(6)).is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::Context;
+use aoc_core::Solution;
+use std::io::BufRead;
+
+pub mod input_parser;
+pub mod item_set;
+
+use input_parser::{subdivide_into_elf_groups, PriorityMap, RucksackItems};
+
+/// Elves carrying the same badge, as given by the puzzle
+pub const GROUP_SIZE: usize = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = RucksackItems;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<RucksackItems> {
+        input_parser::parse_input(reader)
+    }
+
+    fn part_one(rucksacks: &RucksackItems) -> anyhow::Result<i32> {
+        let priority_map = PriorityMap::new();
+
+        let total_priority = rucksacks
+            .iter()
+            .map(|rucksack| {
+                let common_item = rucksack.get_common_item(&priority_map)?;
+                priority_map.try_get_item_priority(&common_item)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context("getting priority")?
+            .into_iter()
+            .sum();
+
+        Ok(total_priority)
+    }
+
+    fn part_two(rucksacks: &RucksackItems) -> anyhow::Result<i32> {
+        let priority_map = PriorityMap::new();
+        let elf_groups = subdivide_into_elf_groups(rucksacks.clone(), GROUP_SIZE)?;
+
+        let group_badge_total_priority = elf_groups
+            .iter()
+            .enumerate()
+            .map(|(i, group)| {
+                let badge = group
+                    .get_group_badge(&priority_map)
+                    .context(format!("finding the badge of group {}", i + 1))?;
+                priority_map.try_get_item_priority(&badge)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context("getting priority")?
+            .into_iter()
+            .sum();
+
+        Ok(group_badge_total_priority)
+    }
+}
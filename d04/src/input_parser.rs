@@ -1,9 +1,6 @@
-use anyhow::{anyhow, Context};
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    ops::RangeInclusive,
-};
+use anyhow::Context;
+use aoc_core::LineError;
+use std::{io::BufRead, ops::RangeInclusive, str::FromStr};
 
 type AssignedRange = RangeInclusive<i32>;
 
@@ -19,53 +16,116 @@ impl ElfPair {
     pub fn range_contains_overlap(&self) -> bool {
         self.0.clone().any(|item| self.1.contains(&item))
     }
+
+    fn range_covers(outer: &AssignedRange, inner: &AssignedRange) -> bool {
+        //An empty range has no items outside of any other range
+        inner.is_empty() || (outer.start() <= inner.start() && inner.end() <= outer.end())
+    }
+
+    /// Same as `range_is_fully_contained_in_other` but only compares the bounds
+    pub fn range_bounds_fully_contained_in_other(&self) -> bool {
+        Self::range_covers(&self.1, &self.0) || Self::range_covers(&self.0, &self.1)
+    }
+
+    /// Same as `range_contains_overlap` but only compares the bounds
+    pub fn range_bounds_overlap(&self) -> bool {
+        !self.0.is_empty()
+            && !self.1.is_empty()
+            && self.0.start() <= self.1.end()
+            && self.1.start() <= self.0.end()
+    }
+}
+
+#[derive(Debug)]
+pub struct ElfPairs(pub Vec<ElfPair>);
+
+impl FromStr for ElfPairs {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        parse_input(input.as_bytes())
+    }
+}
+
+fn parse_range(line: &str, ranges_str: &str) -> Result<AssignedRange, LineError> {
+    let two_numbers: Vec<i32> = ranges_str
+        .split('-')
+        .map(|num_str| {
+            num_str.parse().map_err(|err| {
+                LineError::at_slice(line, num_str, format!("parsing range bound: {}", err))
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let lower_range = two_numbers.first().cloned().ok_or_else(|| {
+        LineError::at_slice(line, ranges_str, "Failed to get lower number in range")
+    })?;
+
+    let upper_range = two_numbers.get(1).cloned().ok_or_else(|| {
+        LineError::at_slice(line, ranges_str, "Failed to get upper number in range")
+    })?;
+
+    Ok(lower_range..=upper_range)
 }
 
-type ElfPairs = Vec<ElfPair>;
+fn parse_elf_pair(line: &str, content: &str) -> Result<ElfPair, LineError> {
+    let two_ranges = content
+        .split(',')
+        .map(|ranges_str| parse_range(line, ranges_str))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let end_of_line = &content[content.len()..];
+
+    let first_in_pair = two_ranges
+        .first()
+        .cloned()
+        .ok_or_else(|| LineError::at_slice(line, end_of_line, "Failed to get first elf in pair"))?;
+
+    let second_in_pair = two_ranges.get(1).cloned().ok_or_else(|| {
+        LineError::at_slice(line, end_of_line, "Failed to get second elf in pair")
+    })?;
+
+    Ok(ElfPair(first_in_pair, second_in_pair))
+}
 
-pub fn parse_input(file: &File) -> anyhow::Result<ElfPairs> {
-    let reader = BufReader::new(file);
-    let mut elf_pairs: ElfPairs = Vec::new();
-    for line in reader.lines() {
+pub fn parse_input(reader: impl BufRead) -> anyhow::Result<ElfPairs> {
+    let mut elf_pairs = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
         let line = line.context("reading line to string")?;
 
         let content = line.trim();
 
-        let two_ranges: Vec<_> = content
-            .split(",")
-            .map(|ranges_str| {
-                let two_numbers: Vec<i32> = ranges_str
-                    .split("-")
-                    .map(|num_str| Ok(num_str.parse()?))
-                    .collect::<anyhow::Result<Vec<i32>>>()?;
-
-                let lower_range = two_numbers
-                    .get(0)
-                    .cloned()
-                    .ok_or_else(|| anyhow!("Failed to get lower number in range"))?;
-
-                let upper_range = two_numbers
-                    .get(1)
-                    .cloned()
-                    .ok_or_else(|| anyhow!("Failed to get upper number in range"))?;
-
-                Ok(lower_range..=upper_range)
-            })
-            .collect::<anyhow::Result<_>>()
-            .context(format!("Getting ranges from line {}", content))?;
+        let elf_pair = parse_elf_pair(&line, content).map_err(|err| err.at_line(i + 1, &line))?;
+
+        elf_pairs.push(elf_pair);
+    }
 
-        let first_in_pair = two_ranges
-            .get(0)
-            .cloned()
-            .ok_or_else(|| anyhow!("Failed to get first elf in pair"))?;
+    Ok(ElfPairs(elf_pairs))
+}
 
-        let second_in_pair = two_ranges
-            .get(1)
-            .cloned()
-            .ok_or_else(|| anyhow!("Failed to get second elf in pair"))?;
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
 
-        elf_pairs.push(ElfPair(first_in_pair, second_in_pair));
+    use super::ElfPair;
+
+    fn elf_pair() -> impl Strategy<Value = ElfPair> {
+        //Small bounds so empty (reversed) ranges and shared edges come up often
+        (-20..20, -20..20, -20..20, -20..20).prop_map(|(a, b, c, d)| ElfPair(a..=b, c..=d))
     }
 
-    Ok(elf_pairs)
+    proptest! {
+        #[test]
+        fn bounds_containment_matches_naive(pair in elf_pair()) {
+            prop_assert_eq!(
+                pair.range_is_fully_contained_in_other(),
+                pair.range_bounds_fully_contained_in_other()
+            );
+        }
+
+        #[test]
+        fn bounds_overlap_matches_naive(pair in elf_pair()) {
+            prop_assert_eq!(pair.range_contains_overlap(), pair.range_bounds_overlap());
+        }
+    }
 }
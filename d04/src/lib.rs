@@ -0,0 +1,36 @@
+use aoc_core::Solution;
+use std::io::BufRead;
+
+pub mod input_parser;
+
+use input_parser::ElfPairs;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = ElfPairs;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<ElfPairs> {
+        input_parser::parse_input(reader)
+    }
+
+    fn part_one(elf_pairs: &ElfPairs) -> anyhow::Result<usize> {
+        Ok(elf_pairs
+            .0
+            .iter()
+            .filter(|pair| pair.range_bounds_fully_contained_in_other())
+            .count())
+    }
+
+    fn part_two(elf_pairs: &ElfPairs) -> anyhow::Result<usize> {
+        Ok(elf_pairs
+            .0
+            .iter()
+            .filter(|pair| pair.range_bounds_overlap())
+            .count())
+    }
+}
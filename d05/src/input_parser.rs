@@ -1,9 +1,6 @@
 use anyhow::{anyhow, Context};
-use std::{
-    collections::VecDeque,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use aoc_core::LineError;
+use std::{collections::VecDeque, fmt::Display, io::BufRead, str::FromStr};
 
 type CrateStack = VecDeque<char>;
 
@@ -56,7 +53,7 @@ impl Ship {
                 .get_mut(instruction.to - 1)
                 .ok_or_else(|| anyhow!("failed to get stack {}", instruction.to))?;
 
-            to_stack.push_front(val.clone());
+            to_stack.push_front(*val);
         }
         Ok(())
     }
@@ -85,30 +82,64 @@ pub struct ParsedInput {
     pub instructions: Instructions,
 }
 
-pub fn parse_input(file: &File) -> anyhow::Result<ParsedInput> {
-    let reader = BufReader::new(file);
+impl FromStr for ParsedInput {
+    type Err = anyhow::Error;
 
-    let mut ship_lines: VecDeque<String> = VecDeque::new();
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        parse_input(input.as_bytes())
+    }
+}
+
+fn get_instruction_val<T>(
+    line: &str,
+    line_vals: &[&str],
+    index: usize,
+    name: &str,
+) -> Result<T, LineError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let val = line_vals.get(index).ok_or_else(|| {
+        LineError::at_slice(line, &line[line.len()..], format!("getting {} val", name))
+    })?;
+
+    val.parse()
+        .map_err(|err| LineError::at_slice(line, val, format!("parsing {} val: {}", name, err)))
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, LineError> {
+    let line_vals: Vec<&str> = line.split(' ').collect();
+
+    Ok(Instruction {
+        move_amount: get_instruction_val(line, &line_vals, 1, "move")?,
+        from: get_instruction_val(line, &line_vals, 3, "from")?,
+        to: get_instruction_val(line, &line_vals, 5, "to")?,
+    })
+}
+
+pub fn parse_input(reader: impl BufRead) -> anyhow::Result<ParsedInput> {
+    let mut ship_lines: VecDeque<(usize, String)> = VecDeque::new();
     //extract out lines related to ship in a stack so they
     //can be parsed backwards from bottom up
-    let mut instruction_lines: Vec<String> = Vec::new();
+    let mut instruction_lines: Vec<(usize, String)> = Vec::new();
     let mut is_instruction = false;
-    for line in reader.lines() {
+    for (i, line) in reader.lines().enumerate() {
         let content = line.context("reading line to string")?;
 
         if content.is_empty() {
             is_instruction = true;
         } else if !is_instruction {
-            ship_lines.push_front(content.to_string());
+            ship_lines.push_front((i + 1, content));
         } else {
-            instruction_lines.push(content);
+            instruction_lines.push((i + 1, content));
         }
     }
 
     //parse lines related to ship
     let mut ship_crate_stacks: Vec<CrateStack> = Vec::new();
 
-    if let Some(starter_line) = ship_lines.pop_front() {
+    if let Some((_, starter_line)) = ship_lines.pop_front() {
         //In the first line we just want to instantiate
         //the stacks
         for _crate_stack_id in starter_line.trim().split("   ") {
@@ -117,7 +148,7 @@ pub fn parse_input(file: &File) -> anyhow::Result<ParsedInput> {
         }
     }
 
-    for line in ship_lines.iter() {
+    for (line_number, line) in ship_lines.iter() {
         //The others we want to iterate through them and
         //push to their respective stacks
         let line_chars = line.chars().collect::<Vec<char>>();
@@ -125,14 +156,24 @@ pub fn parse_input(file: &File) -> anyhow::Result<ParsedInput> {
             if stack_chars.iter().all(|&char| char == ' ') {
                 continue;
             }
+            let column = i_stack * 4 + 1;
             let crate_item = stack_chars
                 .get(1)
                 .cloned()
-                .ok_or_else(|| anyhow!("Unable to get crate item"))?;
-
-            let ship_crate_stack = ship_crate_stacks
-                .get_mut(i_stack)
-                .ok_or_else(|| anyhow!("Unable to get stack"))?;
+                .filter(|crate_item| crate_item.is_ascii_alphabetic())
+                .ok_or_else(|| {
+                    LineError::spanning(column, stack_chars.len(), "Unable to get crate item")
+                        .at_line(*line_number, line)
+                })?;
+
+            let ship_crate_stack = ship_crate_stacks.get_mut(i_stack).ok_or_else(|| {
+                LineError::spanning(
+                    column,
+                    stack_chars.len(),
+                    format!("Unable to get stack {}", i_stack + 1),
+                )
+                .at_line(*line_number, line)
+            })?;
 
             ship_crate_stack.push_front(crate_item);
         }
@@ -141,26 +182,8 @@ pub fn parse_input(file: &File) -> anyhow::Result<ParsedInput> {
     //Parse instructions
     let mut instructions: Instructions = Vec::new();
 
-    for line in instruction_lines.iter() {
-        let line_vals: Vec<&str> = line.split(" ").collect();
-
-        let instruction = Instruction {
-            move_amount: line_vals
-                .get(1)
-                .ok_or_else(|| anyhow!("getting move val"))?
-                .parse()
-                .context("parsing move val")?,
-            from: line_vals
-                .get(3)
-                .ok_or_else(|| anyhow!("getting from val"))?
-                .parse()
-                .context("parsing from val")?,
-            to: line_vals
-                .get(5)
-                .ok_or_else(|| anyhow!("getting to val"))?
-                .parse()
-                .context("parsing to val")?,
-        };
+    for (line_number, line) in instruction_lines.iter() {
+        let instruction = parse_instruction(line).map_err(|err| err.at_line(*line_number, line))?;
 
         instructions.push(instruction)
     }
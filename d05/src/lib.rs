@@ -0,0 +1,43 @@
+use anyhow::Context;
+use aoc_core::Solution;
+use std::io::BufRead;
+
+pub mod input_parser;
+
+use input_parser::ParsedInput;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = ParsedInput;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<ParsedInput> {
+        input_parser::parse_input(reader)
+    }
+
+    fn part_one(parsed: &ParsedInput) -> anyhow::Result<String> {
+        let mut ship = parsed.ship.clone();
+
+        for instruction in parsed.instructions.clone() {
+            ship.apply_instruction(instruction)
+                .context("apply_instruction")?;
+        }
+
+        Ok(ship.get_top_message())
+    }
+
+    fn part_two(parsed: &ParsedInput) -> anyhow::Result<String> {
+        let mut ship = parsed.ship.clone();
+
+        for instruction in parsed.instructions.clone() {
+            ship.apply_instruction_multi(instruction)
+                .context("apply_instruction_multi")?;
+        }
+
+        Ok(ship.get_top_message())
+    }
+}
@@ -1,13 +1,12 @@
 use anyhow::Context;
-use std::{fs::File, io::Read};
+use std::io::BufRead;
 
 pub type DataStreamBuffer = Vec<char>;
-pub fn parse_input(file: &File) -> anyhow::Result<DataStreamBuffer> {
-    let mut file_copy = file.clone();
+pub fn parse_input(mut reader: impl BufRead) -> anyhow::Result<DataStreamBuffer> {
     let mut contents = String::new();
-    file_copy
+    reader
         .read_to_string(&mut contents)
-        .context("reading file to string")?;
+        .context("reading input to string")?;
 
     Ok(contents.chars().collect())
 }
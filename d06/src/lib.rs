@@ -0,0 +1,113 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use std::{collections::HashMap, io::BufRead};
+
+pub mod input_parser;
+
+use input_parser::DataStreamBuffer;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = DataStreamBuffer;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<DataStreamBuffer> {
+        input_parser::parse_input(reader)
+    }
+
+    fn part_one(data_stream: &DataStreamBuffer) -> anyhow::Result<usize> {
+        find_marker_windowed(data_stream, 4)
+    }
+
+    fn part_two(data_stream: &DataStreamBuffer) -> anyhow::Result<usize> {
+        find_marker_windowed(data_stream, 14)
+    }
+}
+
+fn check_contains_duplicates(char_slice: &[char]) -> bool {
+    char_slice.iter().enumerate().any(|(i, &c)| {
+        char_slice
+            .iter()
+            .enumerate()
+            .any(|(i_inner, &c_inner)| i != i_inner && c == c_inner)
+    })
+}
+
+pub fn find_marker(data_stream: DataStreamBuffer, repeat_size: usize) -> anyhow::Result<usize> {
+    data_stream
+        .iter()
+        .enumerate()
+        .find_map(|(i, _x)| {
+            let limited_upper_size = std::cmp::min(i + repeat_size, data_stream.len());
+            let slice = &data_stream[i..limited_upper_size];
+            let contains_duplicates = check_contains_duplicates(slice);
+            if contains_duplicates {
+                None
+            } else {
+                Some(i + repeat_size)
+            }
+        })
+        .ok_or_else(|| anyhow!("No marker found"))
+}
+
+/// Same result as `find_marker` but slides a window over the stream once,
+/// remembering where each char was last seen instead of rechecking every pair
+pub fn find_marker_windowed(data_stream: &[char], repeat_size: usize) -> anyhow::Result<usize> {
+    let mut last_seen: HashMap<char, usize> = HashMap::new();
+    //Start of the longest run without duplicates ending at the current char
+    let mut window_start = 0;
+
+    for (i, c) in data_stream.iter().enumerate() {
+        if let Some(&seen_at) = last_seen.get(c) {
+            window_start = window_start.max(seen_at + 1);
+        }
+        last_seen.insert(*c, i);
+
+        if i + 1 - window_start >= repeat_size {
+            return Ok(i + 1);
+        }
+    }
+
+    //Like `find_marker`, a run without duplicates at the very end of the
+    //stream counts even when it is shorter than the marker
+    if data_stream.is_empty() {
+        Err(anyhow!("No marker found"))
+    } else {
+        Ok(window_start + repeat_size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::{check_contains_duplicates, find_marker, find_marker_windowed};
+
+    #[test]
+    fn test_duplicate_checker() {
+        let slice = &['a', 'a', 'b', 'c'];
+
+        assert!(
+            check_contains_duplicates(slice),
+            "should pick up on duplicate a"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn windowed_marker_matches_naive(
+            //Few distinct chars so duplicates are common, including empty streams
+            data_stream in prop::collection::vec(prop::char::range('a', 'h'), 0..200),
+            repeat_size in 1usize..16,
+        ) {
+            let naive = find_marker(data_stream.clone(), repeat_size).ok();
+            let windowed = find_marker_windowed(&data_stream, repeat_size).ok();
+
+            prop_assert_eq!(naive, windowed);
+        }
+    }
+}
@@ -1,8 +1,6 @@
-use anyhow::{anyhow, Context};
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use anyhow::Context;
+use aoc_core::LineError;
+use std::{io::BufRead, str::FromStr};
 
 #[derive(Debug)]
 pub enum LogLine {
@@ -28,24 +26,38 @@ pub enum CdVal {
     InToDir(String),
 }
 
-type LogLines = Vec<LogLine>;
+#[derive(Debug)]
+pub struct LogLines(pub Vec<LogLine>);
+
+impl FromStr for LogLines {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        parse_input(input.as_bytes())
+    }
+}
 
 type LineComponents<'a> = Vec<&'a str>;
 
+fn get_component<'a>(
+    line: &str,
+    components: &LineComponents<'a>,
+    index: usize,
+    message: &str,
+) -> Result<&'a str, LineError> {
+    components
+        .get(index)
+        .copied()
+        .ok_or_else(|| LineError::at_slice(line, &line[line.len()..], message))
+}
+
 pub fn get_shell_command_from_line_components(
+    line: &str,
     components: LineComponents,
-) -> anyhow::Result<ShellCommand> {
-    let command = match components
-        .get(1)
-        .ok_or_else(|| anyhow!("no 1 index in line components"))?
-        .as_ref()
-    {
+) -> Result<ShellCommand, LineError> {
+    let command = match get_component(line, &components, 1, "Expected a command")? {
         "cd" => {
-            let cd_val = match components
-                .get(2)
-                .ok_or_else(|| anyhow!("Expected val for cd command"))?
-                .as_ref()
-            {
+            let cd_val = match get_component(line, &components, 2, "Expected val for cd command")? {
                 ".." => CdVal::UpDir,
                 dir_name => CdVal::InToDir(dir_name.to_string()),
             };
@@ -53,24 +65,26 @@ pub fn get_shell_command_from_line_components(
             ShellCommand::Cd(cd_val)
         }
         "ls" => ShellCommand::Ls,
-        unknown_cmd => Err(anyhow!("Unexpected command {}", unknown_cmd))?,
+        unknown_cmd => Err(LineError::at_slice(
+            line,
+            unknown_cmd,
+            format!("Unexpected command {}", unknown_cmd),
+        ))?,
     };
     Ok(command)
 }
 
-pub fn get_file_dir_from_line_components(components: LineComponents) -> anyhow::Result<FileDir> {
-    let name = components
-        .get(1)
-        .ok_or_else(|| anyhow!("Expected val for log"))?
-        .to_string();
-    let command = match components
-        .first()
-        .ok_or_else(|| anyhow!("no 1 index in line components"))?
-        .as_ref()
-    {
+pub fn get_file_dir_from_line_components(
+    line: &str,
+    components: LineComponents,
+) -> Result<FileDir, LineError> {
+    let name = get_component(line, &components, 1, "Expected val for log")?.to_string();
+    let command = match get_component(line, &components, 0, "Expected a size or dir")? {
         "dir" => FileDir::Dir { name },
         size_str => {
-            let size: i32 = size_str.parse().context("parsing size from str")?;
+            let size: i32 = size_str.parse().map_err(|err| {
+                LineError::at_slice(line, size_str, format!("parsing size from str: {}", err))
+            })?;
 
             FileDir::File { name, size }
         }
@@ -78,64 +92,63 @@ pub fn get_file_dir_from_line_components(components: LineComponents) -> anyhow::
     Ok(command)
 }
 
-pub fn parse_input(file: &File) -> anyhow::Result<LogLines> {
-    let reader = BufReader::new(file);
+fn parse_log_line(line: &str) -> Result<LogLine, LineError> {
+    let components: Vec<&str> = line.split(' ').collect();
 
+    let log_line = if components.first() == Some(&"$") {
+        LogLine::Command(get_shell_command_from_line_components(line, components)?)
+    } else {
+        LogLine::OutputVal(get_file_dir_from_line_components(line, components)?)
+    };
+
+    Ok(log_line)
+}
+
+pub fn parse_input(reader: impl BufRead) -> anyhow::Result<LogLines> {
     let parsed = reader
         .lines()
-        .map(|line_res| {
+        .enumerate()
+        .map(|(i, line_res)| {
             let line = line_res.context("getting line from reader")?;
-            let components: Vec<&str> = line.split(' ').collect();
-
-            let is_shell_command = components
-                .first()
-                .ok_or_else(|| anyhow!("no 0 index in line components"))?
-                == &"$";
-
-            let log_line = if is_shell_command {
-                let command = get_shell_command_from_line_components(components)
-                    .context("parsing shell command")?;
-                LogLine::Command(command)
-            } else {
-                let file_dir =
-                    get_file_dir_from_line_components(components).context("parsing file_dir")?;
-                LogLine::OutputVal(file_dir)
-            };
+            let log_line = parse_log_line(&line).map_err(|err| err.at_line(i + 1, &line))?;
 
             Ok(log_line)
         })
         .collect::<anyhow::Result<Vec<_>>>()
         .context("parsing input file")?;
 
-    Ok(parsed)
+    Ok(LogLines(parsed))
 }
 
 #[cfg(test)]
 mod test {
-    use std::fs::File;
+    use std::{fs::File, io::BufReader, str::FromStr};
 
     use crate::input_parser::{get_file_dir_from_line_components, CdVal, FileDir, ShellCommand};
 
-    use super::{get_shell_command_from_line_components, parse_input};
+    use super::{get_shell_command_from_line_components, parse_input, LogLine, LogLines};
 
     #[test]
     fn check_shell_command_parse() {
         let command = "$ cd ..";
-        let parsed = get_shell_command_from_line_components(command.split(' ').collect()).unwrap();
+        let parsed =
+            get_shell_command_from_line_components(command, command.split(' ').collect()).unwrap();
 
         assert_eq!(ShellCommand::Cd(CdVal::UpDir), parsed);
     }
     #[test]
     fn check_shell_command_parse_2() {
         let command = "$ cd test";
-        let parsed = get_shell_command_from_line_components(command.split(' ').collect()).unwrap();
+        let parsed =
+            get_shell_command_from_line_components(command, command.split(' ').collect()).unwrap();
 
         assert_eq!(ShellCommand::Cd(CdVal::InToDir("test".to_string())), parsed);
     }
     #[test]
     fn check_shell_command_parse_3() {
         let command = "$ ls";
-        let parsed = get_shell_command_from_line_components(command.split(' ').collect()).unwrap();
+        let parsed =
+            get_shell_command_from_line_components(command, command.split(' ').collect()).unwrap();
 
         assert_eq!(ShellCommand::Ls, parsed);
     }
@@ -143,7 +156,8 @@ mod test {
     #[test]
     fn check_log_parse_1() {
         let command = "139569 fhjlbrmp.phd";
-        let parsed = get_file_dir_from_line_components(command.split(' ').collect()).unwrap();
+        let parsed =
+            get_file_dir_from_line_components(command, command.split(' ').collect()).unwrap();
 
         assert_eq!(
             FileDir::File {
@@ -156,7 +170,8 @@ mod test {
     #[test]
     fn check_log_parse_2() {
         let command = "dir ctctt";
-        let parsed = get_file_dir_from_line_components(command.split(' ').collect()).unwrap();
+        let parsed =
+            get_file_dir_from_line_components(command, command.split(' ').collect()).unwrap();
 
         assert_eq!(
             FileDir::Dir {
@@ -169,6 +184,17 @@ mod test {
     #[test]
     fn full_file_parse_check() {
         let input_file = File::open("./my_input.txt").expect("opening file");
-        parse_input(&input_file).unwrap();
+        parse_input(BufReader::new(input_file)).unwrap();
+    }
+
+    #[test]
+    fn parse_from_str() {
+        let log_lines = LogLines::from_str("$ cd /\n$ ls\ndir a\n14848514 b.txt").unwrap();
+
+        assert_eq!(4, log_lines.0.len());
+        assert!(matches!(
+            &log_lines.0[3],
+            LogLine::OutputVal(FileDir::File { size: 14848514, name }) if name == "b.txt"
+        ));
     }
 }
@@ -1,8 +1,6 @@
 use anyhow::{anyhow, Context, Result};
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use aoc_core::LineError;
+use std::{io::BufRead, str::FromStr};
 
 type TreeHeight = u8;
 type TreeRow = Vec<TreeHeight>;
@@ -71,7 +69,7 @@ impl<'a> Iterator for TreeGridIterator<'a> {
 }
 
 impl TreeGrid {
-    pub fn iter(&self) -> TreeGridIterator {
+    pub fn iter(&self) -> TreeGridIterator<'_> {
         TreeGridIterator {
             tree_grid: self,
             x: 0,
@@ -80,31 +78,28 @@ impl TreeGrid {
     }
 
     fn get_tree_height(&self, x: usize, y: usize) -> Result<&TreeHeight> {
-        Ok(self
-            .grid
+        self.grid
             .get(y)
             .ok_or_else(|| anyhow!("No row at index {}", y))?
             .get(x)
-            .ok_or_else(|| anyhow!("No tree in row at index {}", x))?)
+            .ok_or_else(|| anyhow!("No tree in row at index {}", x))
     }
 
     fn get_tree_row(&self, y: usize) -> Result<&TreeRow> {
-        Ok(self
-            .grid
+        self.grid
             .get(y)
-            .ok_or_else(|| anyhow!("No row at index {}", y))?)
+            .ok_or_else(|| anyhow!("No row at index {}", y))
     }
 
     fn get_tree_col_as_row(&self, x: usize) -> Result<TreeRow> {
-        Ok(self
-            .grid
+        self.grid
             .iter()
             .map(|row| {
                 row.get(x)
-                    .map(|val| *val)
+                    .copied()
                     .ok_or_else(|| anyhow!("No tree in row at index {}", x))
             })
-            .collect::<Result<Vec<_>>>()?)
+            .collect::<Result<Vec<_>>>()
     }
 
     fn calc_no_visible_trees_in_iter<'a>(
@@ -146,16 +141,94 @@ impl TreeGrid {
             .context("calculating current height")?;
 
         let current_row = self.get_tree_row(y).context("getting current row")?;
-        let (left_vis, right_vis) =
-            Self::calc_visible_trees_in_row(current_row, &current_height, x);
+        let (left_vis, right_vis) = Self::calc_visible_trees_in_row(current_row, current_height, x);
 
         let current_col = self.get_tree_col_as_row(x).context("getting current col")?;
         let (top_vis, bottom_vis) =
-            Self::calc_visible_trees_in_row(&current_col, &current_height, y);
+            Self::calc_visible_trees_in_row(&current_col, current_height, y);
 
         Ok(left_vis * right_vis * top_vis * bottom_vis)
     }
 
+    /// How far a tree can see looking back towards the start of `heights`,
+    /// for every tree. Keeps a stack of the trees that are not yet hidden
+    /// behind a taller one so each tree is only pushed and popped once.
+    fn calc_viewing_distances(heights: &[TreeHeight]) -> Vec<i32> {
+        let mut unblocked: Vec<usize> = Vec::new();
+
+        heights
+            .iter()
+            .enumerate()
+            .map(|(i, height)| {
+                while let Some(&j) = unblocked.last() {
+                    if heights[j] >= *height {
+                        break;
+                    }
+                    unblocked.pop();
+                }
+
+                let distance = match unblocked.last() {
+                    Some(&j) => i - j,
+                    None => i,
+                };
+                unblocked.push(i);
+
+                distance as i32
+            })
+            .collect()
+    }
+
+    fn calc_viewing_distances_both_ways(heights: &[TreeHeight]) -> (Vec<i32>, Vec<i32>) {
+        let before = Self::calc_viewing_distances(heights);
+
+        let reversed: TreeRow = heights.iter().rev().copied().collect();
+        let mut after = Self::calc_viewing_distances(&reversed);
+        after.reverse();
+
+        (before, after)
+    }
+
+    /// Every tree's scenic score in the same order as `iter`. Gives the same
+    /// scores as `calc_scenic_score` without rescanning rows for every tree.
+    pub fn calc_scenic_scores(&self) -> Result<Vec<i32>> {
+        let width = self.grid.first().map_or(0, |row| row.len());
+        if let Some((y, row)) = self
+            .grid
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != width)
+        {
+            Err(anyhow!(
+                "Row {} has {} trees, expected {}",
+                y,
+                row.len(),
+                width
+            ))?
+        }
+
+        let columns = (0..width)
+            .map(|x| {
+                let col = self.get_tree_col_as_row(x).context("getting current col")?;
+                Ok(Self::calc_viewing_distances_both_ways(&col))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let scores = self
+            .grid
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                let (left, right) = Self::calc_viewing_distances_both_ways(row);
+                left.into_iter()
+                    .zip(right)
+                    .zip(columns.iter())
+                    .map(move |((l, r), (top, bottom))| l * r * top[y] * bottom[y])
+            })
+            .collect();
+
+        Ok(scores)
+    }
+
     fn check_tree_is_visible_in_row(row: &TreeRow, current_tree: &TreeHeight, pos: usize) -> bool {
         let is_visible_before = row[..pos].iter().all(|tree| tree < current_tree);
 
@@ -214,21 +287,34 @@ impl TreeGrid {
     }
 }
 
-pub fn parse_input(file: &File) -> anyhow::Result<TreeGrid> {
-    let reader = BufReader::new(file);
+impl FromStr for TreeGrid {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        parse_input(input.as_bytes())
+    }
+}
+
+pub fn parse_input(reader: impl BufRead) -> anyhow::Result<TreeGrid> {
     let tree_rows: TreeRows = reader
         .lines()
-        .map(|line| {
+        .enumerate()
+        .map(|(i, line)| {
             let content = line?;
-            content
+            let row = content
                 .chars()
-                .map(|num_str| {
-                    num_str
-                        .to_digit(10)
-                        .map(|num| num as u8)
-                        .ok_or_else(|| anyhow!("Couldn't parsed char as digit {}", num_str))
+                .enumerate()
+                .map(|(column, num_str)| {
+                    num_str.to_digit(10).map(|num| num as u8).ok_or_else(|| {
+                        LineError::new(
+                            column + 1,
+                            format!("Couldn't parsed char as digit {}", num_str),
+                        )
+                        .at_line(i + 1, &content)
+                    })
                 })
-                .collect::<anyhow::Result<Vec<_>>>()
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(row)
         })
         .collect::<anyhow::Result<Vec<_>>>()
         .context("parsing tree rows from reader")?;
@@ -238,6 +324,15 @@ pub fn parse_input(file: &File) -> anyhow::Result<TreeGrid> {
 
 #[cfg(test)]
 mod test {
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn parse_from_str() {
+        let tree_grid = super::TreeGrid::from_str("123\n456").unwrap();
+        assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], tree_grid.grid);
+    }
+
     #[test]
     fn check_vis_calc() {
         let tree_row: super::TreeRow = vec![1, 2, 3, 4];
@@ -261,4 +356,31 @@ mod test {
         assert_eq!(up, 2);
         assert_eq!(down, 1);
     }
+
+    #[test]
+    fn ragged_grid_scores_are_an_error() {
+        let tree_grid = super::TreeGrid::from_str("123\n45").unwrap();
+        assert!(tree_grid.calc_scenic_scores().is_err());
+    }
+
+    fn tree_grid() -> impl Strategy<Value = super::TreeGrid> {
+        //Includes empty, single-row and single-column grids
+        (0usize..12, 0usize..12).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(0u8..10, width), height)
+                .prop_map(|grid| super::TreeGrid { grid })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn scenic_scores_match_naive(tree_grid in tree_grid()) {
+            let naive = tree_grid
+                .iter()
+                .map(|tree| tree.calc_scenic_score())
+                .collect::<anyhow::Result<Vec<_>>>()
+                .unwrap();
+
+            prop_assert_eq!(naive, tree_grid.calc_scenic_scores().unwrap());
+        }
+    }
 }
@@ -0,0 +1,74 @@
+use anyhow::Context;
+use aoc_core::Solution;
+use std::io::BufRead;
+
+pub mod input_parser;
+
+use input_parser::TreeGrid;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = TreeGrid;
+    type PartOne = usize;
+    type PartTwo = i32;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<TreeGrid> {
+        input_parser::parse_input(reader)
+    }
+
+    fn part_one(tree_grid: &TreeGrid) -> anyhow::Result<usize> {
+        let visibilities = tree_grid
+            .iter()
+            .map(|tree| tree.is_visible())
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context("failed visible check")?;
+
+        Ok(visibilities.into_iter().filter(|&visible| visible).count())
+    }
+
+    fn part_two(tree_grid: &TreeGrid) -> anyhow::Result<i32> {
+        tree_grid
+            .calc_scenic_scores()
+            .context("Failed calculating score")?
+            .into_iter()
+            .max()
+            .context("Should have been items iter of trees")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs::File, io::BufReader};
+
+    use crate::input_parser;
+
+    #[test]
+    fn given_example_part_1() {
+        let input_file = File::open("./example.txt").expect("opening file");
+        let parsed = input_parser::parse_input(BufReader::new(input_file)).expect("parsing file");
+
+        let visible_trees_count = parsed
+            .iter()
+            .filter(|tree| tree.is_visible().expect("failed visible check"))
+            .count();
+
+        assert_eq!(21, visible_trees_count);
+    }
+
+    #[test]
+    fn given_example_part_2() {
+        let input_file = File::open("./example.txt").expect("opening file");
+        let parsed = input_parser::parse_input(BufReader::new(input_file)).expect("parsing file");
+
+        let max_scenic_score = parsed
+            .iter()
+            .map(|tree| tree.calc_scenic_score().expect("Failed calculating score"))
+            .max()
+            .expect("Should have been items iter of trees");
+
+        assert_eq!(8, max_scenic_score);
+    }
+}
@@ -1,45 +1,91 @@
-use anyhow::{anyhow, Context, Result};
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use anyhow::{Context, Result};
+use aoc_core::LineError;
+use std::{io::BufRead, str::FromStr};
 
 use crate::rope_simulator::{Direction, Motion};
 
-type Motions = Vec<Motion>;
+#[derive(Debug, PartialEq)]
+pub struct Motions(pub Vec<Motion>);
 
-pub fn parse_input(file: &File) -> Result<Motions> {
-    let reader = BufReader::new(file);
-    let motions: Motions = reader
+impl FromStr for Motions {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        parse_input(input.as_bytes())
+    }
+}
+
+fn parse_motion(line: &str) -> std::result::Result<Motion, LineError> {
+    let direction = match line
+        .chars()
+        .next()
+        .ok_or_else(|| LineError::new(1, "No first char in line"))?
+    {
+        'U' => Direction::Up,
+        'D' => Direction::Down,
+        'L' => Direction::Left,
+        'R' => Direction::Right,
+        bad_char => Err(LineError::new(
+            1,
+            format!("Non direction char, {}", bad_char),
+        ))?,
+    };
+
+    let amount_str = line
+        .char_indices()
+        .nth(2)
+        .map_or(&line[line.len()..], |(start, _)| &line[start..]);
+
+    let amount = amount_str.parse().map_err(|err| {
+        LineError::at_slice(line, amount_str, format!("Failed parsing amount: {}", err))
+    })?;
+
+    Ok(Motion { direction, amount })
+}
+
+pub fn parse_input(reader: impl BufRead) -> Result<Motions> {
+    let motions = reader
         .lines()
-        .map(|line| {
+        .enumerate()
+        .map(|(i, line)| {
             let content = line?;
-            let line_chars = content.chars().collect::<Vec<_>>();
-
-            let direction = match line_chars
-                .first()
-                .ok_or_else(|| anyhow!("No first char in line"))?
-            {
-                'U' => Direction::Up,
-                'D' => Direction::Down,
-                'L' => Direction::Left,
-                'R' => Direction::Right,
-                bad_char => Err(anyhow!("Non direction char, {}", bad_char))?,
-            };
-
-            let amount = line_chars[2..]
-                .iter()
-                .collect::<String>()
-                .parse()
-                .context("Failed parsing amount")?;
-
-            Ok(Motion { direction, amount })
+            let motion = parse_motion(&content).map_err(|err| err.at_line(i + 1, &content))?;
+            Ok(motion)
         })
         .collect::<anyhow::Result<Vec<_>>>()
         .context("parsing motions from reader")?;
 
-    Ok(motions)
+    Ok(Motions(motions))
 }
 
 #[cfg(test)]
-mod test {}
+mod test {
+    use aoc_core::ParseError;
+    use std::str::FromStr;
+
+    use super::Motions;
+    use crate::rope_simulator::{
+        Direction::{Left, Up},
+        Motion,
+    };
+
+    #[test]
+    fn parse_from_str() {
+        let motions = Motions::from_str("U 12\nL 3").unwrap();
+
+        assert_eq!(
+            Motions(vec![Motion::new(Up, 12), Motion::new(Left, 3)]),
+            motions
+        );
+    }
+
+    #[test]
+    fn bad_amount_reports_line_and_column() {
+        let err = Motions::from_str("U 12\nL 3\nR 1a").unwrap_err();
+        let parse_error = err.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(3, parse_error.line_number);
+        assert_eq!(3, parse_error.column);
+        assert_eq!(2, parse_error.len);
+    }
+}
@@ -1,50 +1,105 @@
 use anyhow::Context;
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{i32, multispace0},
-    IResult,
-};
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Clone)]
+use aoc_core::LineError;
+use nom::{branch::alt, bytes::complete::tag, character::complete::i32, IResult};
+use std::{io::BufRead, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Signal {
     AddX(i32),
     NoOp,
 }
-fn parse_addx(input: &str) -> IResult<&str, Signal> {
-    let (input, _) = tag("addx")(input)?;
-    let (input, _) = multispace0(input)?;
-    let (input, value) = i32(input)?;
-    Ok((input, Signal::AddX(value)))
+fn parse_opcode(input: &str) -> IResult<&str, &str> {
+    alt((tag("addx"), tag("noop")))(input)
+}
+
+fn parse_value(input: &str) -> IResult<&str, i32> {
+    i32(input)
 }
 
-fn parse_noop(input: &str) -> IResult<&str, Signal> {
-    let (input, _) = tag("noop")(input)?;
-    Ok((input, Signal::NoOp))
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalRegister(pub Vec<Signal>);
+
+impl FromStr for SignalRegister {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        parse_input(input.as_bytes())
+    }
 }
 
-fn parse_signal(input: &str) -> IResult<&str, Signal> {
-    alt((parse_addx, parse_noop))(input)
+fn parse_line(line: &str) -> Result<Signal, LineError> {
+    let (rest, opcode) = parse_opcode(line).map_err(|_| {
+        let opcode = line
+            .split_whitespace()
+            .next()
+            .unwrap_or(&line[line.len()..]);
+        LineError::at_slice(line, opcode, "Expected addx or noop signal")
+    })?;
+    let operand = rest.trim();
+
+    match opcode {
+        "addx" if operand.is_empty() => Err(LineError::at_slice(
+            line,
+            &rest[rest.len()..],
+            "Expected a value after addx",
+        )),
+        "addx" => match parse_value(operand) {
+            Ok(("", value)) => Ok(Signal::AddX(value)),
+            _ => Err(LineError::at_slice(
+                line,
+                operand,
+                format!("{} is not a valid addx value", operand),
+            )),
+        },
+        _ if !operand.is_empty() => Err(LineError::at_slice(
+            line,
+            operand,
+            "Unexpected input after signal",
+        )),
+        _ => Ok(Signal::NoOp),
+    }
 }
 
-type SignalRegister = Vec<Signal>;
-pub fn parse_input(file: &File) -> SignalRegister {
-    let reader = BufReader::new(file);
-    let signals: SignalRegister = reader
+pub fn parse_input(reader: impl BufRead) -> anyhow::Result<SignalRegister> {
+    let signals = reader
         .lines()
-        .map(|line| {
-            let content = line.expect("parsing");
-            let (_, signal) = parse_signal(content.as_str()).expect("parsing");
-            signal
+        .enumerate()
+        .map(|(i, line)| {
+            let content = line.context("reading line to string")?;
+            let signal = parse_line(&content).map_err(|err| err.at_line(i + 1, &content))?;
+            Ok(signal)
         })
-        .collect();
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
-    signals
+    Ok(SignalRegister(signals))
 }
 
 #[cfg(test)]
-mod test {}
+mod test {
+    use aoc_core::ParseError;
+    use std::str::FromStr;
+
+    use super::{Signal, SignalRegister};
+
+    #[test]
+    fn parse_from_str() {
+        let signals = SignalRegister::from_str("noop\naddx 3\naddx -5").unwrap();
+
+        assert_eq!(
+            SignalRegister(vec![Signal::NoOp, Signal::AddX(3), Signal::AddX(-5)]),
+            signals
+        );
+    }
+
+    #[test]
+    fn bad_value_reports_line_and_column() {
+        let err = SignalRegister::from_str("noop\naddx 3\naddx x").unwrap_err();
+        let parse_error = err.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(3, parse_error.line_number);
+        assert_eq!(6, parse_error.column);
+        assert_eq!(1, parse_error.len);
+        assert_eq!("addx x", parse_error.snippet);
+        assert_eq!("x", &parse_error.snippet[parse_error.column - 1..]);
+    }
+}
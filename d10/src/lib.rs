@@ -0,0 +1,81 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use std::io::BufRead;
+
+pub mod input_parser;
+
+use input_parser::{Signal, SignalRegister};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = SignalRegister;
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<SignalRegister> {
+        input_parser::parse_input(reader)
+    }
+
+    fn part_one(signals: &SignalRegister) -> anyhow::Result<i32> {
+        let mut program = ProgramExecution::new();
+        program.execute_signals(signals.0.clone());
+
+        let signal_strength: i32 = (0..6)
+            .map(|i| (i * 40) + 20)
+            .filter_map(|cycle| program.get_signal_strength_at_cycle(cycle))
+            .sum();
+
+        Ok(signal_strength)
+    }
+
+    fn part_two(_signals: &SignalRegister) -> anyhow::Result<String> {
+        Err(anyhow!("Part two has not been solved yet"))
+    }
+}
+
+pub struct ProgramExecution {
+    x: i32,
+    cycles: Vec<i32>,
+}
+
+impl Default for ProgramExecution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgramExecution {
+    pub fn new() -> Self {
+        Self {
+            x: 1,
+            cycles: Vec::new(),
+        }
+    }
+
+    fn execute_signal(&mut self, signal: Signal) {
+        match signal {
+            Signal::NoOp => self.cycles.push(self.x),
+            Signal::AddX(v) => {
+                self.cycles.extend(vec![self.x, self.x]);
+                self.x += v;
+            }
+        }
+    }
+
+    pub fn execute_signals(&mut self, signals: Vec<Signal>) {
+        for signal in signals {
+            self.execute_signal(signal);
+        }
+    }
+
+    pub fn get_x_at_cycle(&self, cycle: usize) -> Option<&i32> {
+        self.cycles.get(cycle - 1)
+    }
+
+    pub fn get_signal_strength_at_cycle(&self, cycle: usize) -> Option<i32> {
+        self.get_x_at_cycle(cycle).map(|x| x * (cycle as i32))
+    }
+}